@@ -8,6 +8,32 @@ fn version(c: &mut Criterion) {
             let _ = version.normalized_categories();
         })
     });
+    let few_codepoints = black_box(['a' as u32, 'Z' as u32, 0x2603]);
+    c.bench_function("version - categories_of - few", |b| {
+        b.iter(|| {
+            let _ = version.categories_of(&few_codepoints);
+        })
+    });
+    c.bench_function("version - category_of - few", |b| {
+        b.iter(|| {
+            for codepoint in few_codepoints {
+                let _ = version.category_of(codepoint);
+            }
+        })
+    });
+    let many_codepoints: Vec<_> = black_box((0..0x10000).step_by(7).collect());
+    c.bench_function("version - categories_of - many", |b| {
+        b.iter(|| {
+            let _ = version.categories_of(&many_codepoints);
+        })
+    });
+    c.bench_function("version - category_of - many", |b| {
+        b.iter(|| {
+            for codepoint in &many_codepoints {
+                let _ = version.category_of(*codepoint);
+            }
+        })
+    });
 }
 
 fn intervals(c: &mut Criterion) {
@@ -70,6 +96,11 @@ fn query(c: &mut Criterion) {
                 internals::query::intervals_for_set(version, black_box(UnicodeCategory::Lu).into());
         })
     });
+    c.bench_function("query - intervals_for_set - group", |b| {
+        b.iter(|| {
+            let _ = internals::query::intervals_for_set(version, black_box(UnicodeCategory::L));
+        })
+    });
     c.bench_function("query - intervals_for_set - multiple", |b| {
         b.iter(|| {
             let _ = internals::query::intervals_for_set(
@@ -127,6 +158,11 @@ fn query(c: &mut Criterion) {
         .include_categories(UnicodeCategory::UPPERCASE_LETTER)
         .interval_set()
         .expect("Invalid query input");
+    c.bench_function("query - interval set - cached category", |b| {
+        b.iter(|| {
+            let _ = version.interval_set_for_category(black_box(UnicodeCategory::Lo));
+        })
+    });
     c.bench_function("query - interval set - codepoint_at", |b| {
         b.iter(|| {
             let _ = interval_set.codepoint_at(27);
@@ -1,7 +1,7 @@
-use crate::{constants::ALL_CATEGORIES, error};
+use crate::{constants::ALL_CATEGORIES, error, IntervalQuery};
 use core::{
     fmt,
-    ops::{BitOr, BitOrAssign},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign},
     str::FromStr,
 };
 use UnicodeCategory::*;
@@ -106,11 +106,23 @@ impl FromStr for UnicodeCategory {
             "Lt" => Lt,
             "Cn" => Cn,
             "Lu" => Lu,
-            _ => return Err(Self::Err::InvalidCategory(s.to_owned().into_boxed_str())),
+            _ => {
+                return from_full_name(s)
+                    .ok_or_else(|| Self::Err::InvalidCategory(s.to_owned().into_boxed_str()))
+            }
         })
     }
 }
 
+/// Parse a full category name, e.g. "Uppercase Letter".
+/// The name is case-insensitive and words could be separated by spaces or underscores.
+fn from_full_name(name: &str) -> Option<UnicodeCategory> {
+    let normalized = name.replace('_', " ");
+    UnicodeCategorySet::all()
+        .into_iter()
+        .find(|category| category.full_name().eq_ignore_ascii_case(&normalized))
+}
+
 impl UnicodeCategory {
     /// Letters.
     pub const L: UnicodeCategorySet = UnicodeCategorySet(
@@ -240,6 +252,63 @@ impl UnicodeCategory {
             Lu => "Lu",
         }
     }
+
+    /// Full name, e.g. "Uppercase Letter".
+    #[must_use]
+    pub const fn full_name(self) -> &'static str {
+        match self {
+            Pe => "Close Punctuation",
+            Pc => "Connector Punctuation",
+            Cc => "Control",
+            Sc => "Currency Symbol",
+            Pd => "Dash Punctuation",
+            Nd => "Decimal Number",
+            Me => "Enclosing Mark",
+            Pf => "Final Punctuation",
+            Cf => "Format",
+            Pi => "Initial Punctuation",
+            Nl => "Letter Number",
+            Zl => "Line Separator",
+            Ll => "Lowercase Letter",
+            Sm => "Math Symbol",
+            Lm => "Modifier Letter",
+            Sk => "Modifier Symbol",
+            Mn => "Nonspacing Mark",
+            Ps => "Open Punctuation",
+            Lo => "Other Letter",
+            No => "Other Number",
+            Po => "Other Punctuation",
+            So => "Other Symbol",
+            Zp => "Paragraph Separator",
+            Co => "Private Use",
+            Zs => "Space Separator",
+            Mc => "Spacing Mark",
+            Cs => "Surrogate",
+            Lt => "Titlecase Letter",
+            Cn => "Unassigned",
+            Lu => "Uppercase Letter",
+        }
+    }
+
+    /// A query for the latest Unicode version that includes this category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeCategory;
+    ///
+    /// let intervals = UnicodeCategory::Lu
+    ///     .query()
+    ///     .max_codepoint(90)
+    ///     .intervals()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(intervals, &[(65, 90)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn query(self) -> IntervalQuery<'static> {
+        crate::query().include_categories(self)
+    }
 }
 
 impl fmt::Display for UnicodeCategory {
@@ -265,6 +334,139 @@ impl UnicodeCategorySet {
     pub const fn all() -> Self {
         Self(ALL_CATEGORIES)
     }
+    /// A set with all categories from the given slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeCategorySet};
+    /// const CASED: UnicodeCategorySet = UnicodeCategorySet::from_slice(&[
+    ///     UnicodeCategory::Ll,
+    ///     UnicodeCategory::Lt,
+    ///     UnicodeCategory::Lu,
+    /// ]);
+    /// assert_eq!(CASED.len(), 3);
+    /// ```
+    #[must_use]
+    pub const fn from_slice(categories: &[UnicodeCategory]) -> Self {
+        let mut value = 0;
+        let mut idx = 0;
+        // `idx` is always less than the slice length and the shift is always < 30
+        #[allow(clippy::arithmetic_side_effects)]
+        while idx < categories.len() {
+            value |= 1 << categories[idx] as u32;
+            idx += 1;
+        }
+        Self(value)
+    }
+    /// Categories that a query with the given `include` and `exclude` categories will use.
+    ///
+    /// If `include` is `None`, then all categories are included. Categories from `exclude`
+    /// are always removed from the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeCategorySet};
+    /// let categories = UnicodeCategorySet::effective(
+    ///     Some(UnicodeCategory::L),
+    ///     UnicodeCategory::Lu.into()
+    /// );
+    /// assert_eq!(categories.to_string(), "Ll, Lm, Lo, Lt");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn effective(
+        include: Option<UnicodeCategorySet>,
+        exclude: UnicodeCategorySet,
+    ) -> UnicodeCategorySet {
+        merge(include, exclude)
+    }
+    /// Categories of this set that belong to `group`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let categories = (UnicodeCategory::Lu | UnicodeCategory::Nd)
+    ///     .exclusive_in_group(UnicodeCategory::L);
+    /// assert_eq!(categories, UnicodeCategory::Lu.into());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn exclusive_in_group(self, group: UnicodeCategorySet) -> UnicodeCategorySet {
+        self.intersection(group)
+    }
+    /// Categories that are in either of sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let categories = UnicodeCategory::Z.union(UnicodeCategory::Cc.into());
+    /// assert_eq!(categories.to_string(), "Cc, Zl, Zp, Zs");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: UnicodeCategorySet) -> UnicodeCategorySet {
+        Self(self.0 | other.0)
+    }
+    /// Categories that are in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let categories = (UnicodeCategory::Lu | UnicodeCategory::Nd).intersection(UnicodeCategory::N);
+    /// assert_eq!(categories, UnicodeCategory::Nd.into());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: UnicodeCategorySet) -> UnicodeCategorySet {
+        Self(self.0 & other.0)
+    }
+    /// Categories that are in this set, but not in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let categories = UnicodeCategory::L.difference(UnicodeCategory::Lm.into());
+    /// assert_eq!(categories.to_string(), "Ll, Lo, Lt, Lu");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: UnicodeCategorySet) -> UnicodeCategorySet {
+        Self(self.0 & !other.0)
+    }
+    /// Categories that are in exactly one of sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let categories = UnicodeCategory::Z.symmetric_difference(UnicodeCategory::Zs | UnicodeCategory::Cc);
+    /// assert_eq!(categories.to_string(), "Cc, Zl, Zp");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: UnicodeCategorySet) -> UnicodeCategorySet {
+        Self(self.0 ^ other.0)
+    }
+    /// All Unicode categories that are not in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeCategorySet};
+    /// let categories = (UnicodeCategory::L | UnicodeCategory::M | UnicodeCategory::N).complement();
+    /// assert_eq!(categories, UnicodeCategory::P | UnicodeCategory::S | UnicodeCategory::Z | UnicodeCategory::C);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> UnicodeCategorySet {
+        Self(!self.0 & ALL_CATEGORIES)
+    }
     /// Create a category set, but do not check whether the input value is valid.
     #[inline]
     #[must_use]
@@ -281,6 +483,11 @@ impl UnicodeCategorySet {
     pub fn remove(&mut self, category: UnicodeCategory) {
         self.unset(category as u8);
     }
+    /// Add a Unicode category to the set if it is absent, otherwise remove it.
+    #[inline]
+    pub fn toggle(&mut self, category: UnicodeCategory) {
+        self.flip(category as u8);
+    }
     /// Whether the set contains `category`.
     #[inline]
     #[must_use]
@@ -299,6 +506,45 @@ impl UnicodeCategorySet {
     pub const fn is_empty(self) -> bool {
         self.0 == 0
     }
+    /// Whether all categories of this set are in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// assert!((UnicodeCategory::Lu | UnicodeCategory::Ll).is_subset(UnicodeCategory::L));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_subset(self, other: UnicodeCategorySet) -> bool {
+        self.0 & other.0 == self.0
+    }
+    /// Whether all categories of `other` are in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// assert!(UnicodeCategory::L.is_superset(UnicodeCategory::Lu | UnicodeCategory::Ll));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_superset(self, other: UnicodeCategorySet) -> bool {
+        other.is_subset(self)
+    }
+    /// Whether this set and `other` have no categories in common.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// assert!(UnicodeCategory::L.is_disjoint(UnicodeCategory::N));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_disjoint(self, other: UnicodeCategorySet) -> bool {
+        self.0 & other.0 == 0
+    }
     /// Extract the inner storage value.
     #[inline]
     #[must_use]
@@ -311,21 +557,42 @@ impl UnicodeCategorySet {
     pub const fn iter(self) -> Iter {
         Iter { data: self }
     }
+    /// Abbreviations of included Unicode categories sorted alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let set = UnicodeCategory::Lu | UnicodeCategory::Cc;
+    /// assert_eq!(set.to_abbr_vec(), vec!["Cc", "Lu"]);
+    /// ```
+    #[must_use]
+    pub fn to_abbr_vec(self) -> Vec<&'static str> {
+        let mut abbreviations: Vec<_> = self.iter().map(UnicodeCategory::as_str).collect();
+        abbreviations.sort_unstable();
+        abbreviations
+    }
     // `index` is always < 30 and can't overflow
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     pub(crate) fn set(&mut self, index: u8) {
         self.0 |= 1 << index;
     }
     // `index` is always < 30 and can't overflow
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     pub(crate) fn unset(&mut self, index: u8) {
         self.0 &= !(1 << index);
     }
+    // `index` is always < 30 and can't overflow
+    #[inline]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn flip(&mut self, index: u8) {
+        self.0 ^= 1 << index;
+    }
     // `index`` is always < 30 and can't overflow
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     const fn is_set(self, index: u8) -> bool {
         self.0 & (1 << index) != 0
     }
@@ -340,7 +607,7 @@ impl Default for UnicodeCategorySet {
 
 impl fmt::Display for UnicodeCategorySet {
     // `idx` can't overflow as the maximum possible size of `iter` is 30 < usize::MAX
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let len = self.len();
         for (idx, category) in self.iter().enumerate() {
@@ -353,12 +620,38 @@ impl fmt::Display for UnicodeCategorySet {
     }
 }
 
+impl FromStr for UnicodeCategorySet {
+    type Err = error::Error;
+
+    /// Parse a comma-separated list of categories, e.g. "Ll, Lu".
+    /// Group letters, like "L" or "P", are expanded to all categories of the group.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = UnicodeCategorySet::new();
+        if s.trim().is_empty() {
+            return Ok(set);
+        }
+        for token in s.split(',').map(str::trim) {
+            set |= match token {
+                "L" => UnicodeCategory::L,
+                "M" => UnicodeCategory::M,
+                "N" => UnicodeCategory::N,
+                "P" => UnicodeCategory::P,
+                "S" => UnicodeCategory::S,
+                "Z" => UnicodeCategory::Z,
+                "C" => UnicodeCategory::C,
+                _ => UnicodeCategory::from_str(token)?.into(),
+            };
+        }
+        Ok(set)
+    }
+}
+
 impl BitOr for UnicodeCategory {
     type Output = UnicodeCategorySet;
 
     // `self` and `rhs` are both < 30; Therefore shift won't overflow
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     fn bitor(self, rhs: Self) -> Self::Output {
         UnicodeCategorySet(1 << self as u8 | 1 << rhs as u8)
     }
@@ -378,7 +671,7 @@ impl BitOr<UnicodeCategory> for UnicodeCategorySet {
 
     // `rhs as u8` can't overflow as it has only 30 elements
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     fn bitor(self, rhs: UnicodeCategory) -> Self::Output {
         Self(self.into_value() | 1 << rhs as u8)
     }
@@ -407,19 +700,89 @@ impl BitOrAssign<UnicodeCategory> for UnicodeCategorySet {
     }
 }
 
+impl BitAnd<UnicodeCategorySet> for UnicodeCategorySet {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: UnicodeCategorySet) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAnd<UnicodeCategory> for UnicodeCategorySet {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: UnicodeCategory) -> Self::Output {
+        self.intersection(rhs.into())
+    }
+}
+
+impl BitAndAssign<UnicodeCategorySet> for UnicodeCategorySet {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: UnicodeCategorySet) {
+        self.0 &= rhs.into_value();
+    }
+}
+
+impl BitAndAssign<UnicodeCategory> for UnicodeCategorySet {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: UnicodeCategory) {
+        *self &= UnicodeCategorySet::from(rhs);
+    }
+}
+
+impl Sub<UnicodeCategorySet> for UnicodeCategorySet {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: UnicodeCategorySet) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign<UnicodeCategorySet> for UnicodeCategorySet {
+    #[inline]
+    fn sub_assign(&mut self, rhs: UnicodeCategorySet) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl FromIterator<UnicodeCategory> for UnicodeCategorySet {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = UnicodeCategory>>(iter: T) -> Self {
+        let mut set = UnicodeCategorySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<UnicodeCategory> for UnicodeCategorySet {
+    #[inline]
+    fn extend<T: IntoIterator<Item = UnicodeCategory>>(&mut self, iter: T) {
+        for category in iter {
+            self.add(category);
+        }
+    }
+}
+
+impl Extend<UnicodeCategorySet> for UnicodeCategorySet {
+    #[inline]
+    fn extend<T: IntoIterator<Item = UnicodeCategorySet>>(&mut self, iter: T) {
+        for set in iter {
+            *self |= set;
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Iter {
     data: UnicodeCategorySet,
 }
 
-impl Iterator for Iter {
-    type Item = UnicodeCategory;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        // INVARIANT: The number of trailing zeros for `u32` is 32 at most which is less than `u8::MAX`
-        #[allow(clippy::cast_possible_truncation)]
-        let index = self.data.0.trailing_zeros() as u8;
-        let category = match index {
+impl Iter {
+    const fn category_at(index: u8) -> Option<UnicodeCategory> {
+        Some(match index {
             0 => Pe,
             1 => Pc,
             2 => Cc,
@@ -451,7 +814,34 @@ impl Iterator for Iter {
             28 => Cn,
             29 => Lu,
             _ => return None,
-        };
+        })
+    }
+}
+
+impl Iterator for Iter {
+    type Item = UnicodeCategory;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // INVARIANT: The number of trailing zeros for `u32` is 32 at most which is less than `u8::MAX`
+        #[allow(clippy::cast_possible_truncation)]
+        let index = self.data.0.trailing_zeros() as u8;
+        let category = Iter::category_at(index)?;
+        self.data.unset(index);
+        Some(category)
+    }
+}
+
+impl DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        // INVARIANTS:
+        //   - The set is not empty, hence the number of leading zeros is at most 31
+        //   - The result is at most 31 which is less than `u8::MAX`
+        #[allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
+        let index = (31 - self.data.0.leading_zeros()) as u8;
+        let category = Iter::category_at(index)?;
         self.data.unset(index);
         Some(category)
     }
@@ -464,10 +854,30 @@ impl ExactSizeIterator for Iter {
     }
 }
 
+impl IntoIterator for UnicodeCategorySet {
+    type Item = UnicodeCategory;
+    type IntoIter = Iter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &UnicodeCategorySet {
+    type Item = UnicodeCategory;
+    type IntoIter = Iter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl From<UnicodeCategory> for UnicodeCategorySet {
     // `category as u8` can't overflow as it has only 30 elements
     #[inline]
-    #[allow(clippy::integer_arithmetic)]
+    #[allow(clippy::arithmetic_side_effects)]
     fn from(category: UnicodeCategory) -> Self {
         Self::from_value_unchecked(1 << category as u8)
     }
@@ -510,6 +920,61 @@ mod tests {
     };
     use test_case::test_case;
 
+    #[test]
+    fn test_category_query() {
+        assert_eq!(
+            UnicodeCategory::Nd.query(),
+            crate::query().include_categories(UnicodeCategory::Nd)
+        );
+    }
+
+    #[test_case("Lu", Lu)]
+    #[test_case("Uppercase Letter", Lu)]
+    #[test_case("uppercase letter", Lu)]
+    #[test_case("UPPERCASE_LETTER", Lu)]
+    #[test_case("Connector Punctuation", Pc)]
+    #[test_case("connector_punctuation", Pc)]
+    #[test_case("Control", Cc)]
+    #[test_case("Unassigned", Cn)]
+    fn test_category_from_str(value: &str, expected: UnicodeCategory) {
+        assert_eq!(
+            UnicodeCategory::from_str(value).expect("Invalid category"),
+            expected
+        );
+    }
+
+    #[test_case(Lu, "Uppercase Letter")]
+    #[test_case(Pc, "Connector Punctuation")]
+    #[test_case(Cn, "Unassigned")]
+    fn test_category_full_name(category: UnicodeCategory, expected: &str) {
+        assert_eq!(category.full_name(), expected);
+    }
+
+    #[test]
+    fn test_category_full_name_roundtrip() {
+        for category in UnicodeCategorySet::all() {
+            assert_eq!(
+                UnicodeCategory::from_str(category.full_name()).expect("Invalid category"),
+                category
+            );
+            let alias = category.full_name().to_ascii_uppercase().replace(' ', "_");
+            assert_eq!(
+                UnicodeCategory::from_str(&alias).expect("Invalid category"),
+                category
+            );
+        }
+    }
+
+    #[test_case("lu")]
+    #[test_case("Uppercase  Letter")]
+    #[test_case("Uppercase-Letter")]
+    fn test_category_from_str_invalid(value: &str) {
+        assert_eq!(
+            UnicodeCategory::from_str(value).expect_err("Should fail"),
+            error::Error::InvalidCategory(value.into())
+        );
+    }
+
     #[test]
     fn test_category_from_str_error() {
         assert_eq!(
@@ -525,7 +990,7 @@ mod tests {
     fn test_category_traits() {
         let mut hasher = DefaultHasher::new();
         Ll.hash(&mut hasher);
-        hasher.finish();
+        let _ = hasher.finish();
         let _ = Ll.clone();
         assert_eq!(format!("{Ll:?}"), "Ll");
     }
@@ -540,6 +1005,47 @@ mod tests {
         assert_eq!(UnicodeCategory::L.to_string(), "Ll, Lm, Lo, Lt, Lu");
     }
 
+    #[test_case("", UnicodeCategorySet::new())]
+    #[test_case("Lu", Lu.into())]
+    #[test_case("Ll, Lm, Lo, Lt, Lu", UnicodeCategory::L)]
+    #[test_case(" Lu ,Nd", Lu | Nd)]
+    #[test_case("L,P", UnicodeCategory::L | UnicodeCategory::P)]
+    #[test_case("Z, Cc", UnicodeCategory::Z | Cc)]
+    #[test_case("Lu, Lu", Lu.into())]
+    fn test_set_from_str(value: &str, expected: UnicodeCategorySet) {
+        assert_eq!(
+            UnicodeCategorySet::from_str(value).expect("Invalid categories"),
+            expected
+        );
+    }
+
+    #[test_case(UnicodeCategorySet::new())]
+    #[test_case(UnicodeCategory::L)]
+    #[test_case(UnicodeCategorySet::all())]
+    fn test_set_from_str_roundtrip(set: UnicodeCategorySet) {
+        assert_eq!(
+            UnicodeCategorySet::from_str(&set.to_string()).expect("Invalid categories"),
+            set
+        );
+    }
+
+    #[test_case("Lu, Xx", "Xx")]
+    #[test_case("Lu,,Ll", "")]
+    #[test_case("X", "X")]
+    fn test_set_from_str_error(value: &str, token: &str) {
+        assert_eq!(
+            UnicodeCategorySet::from_str(value).expect_err("Should fail"),
+            error::Error::InvalidCategory(token.into())
+        );
+    }
+
+    #[test_case(UnicodeCategorySet::new(), &[])]
+    #[test_case(Lu | Cc, &["Cc", "Lu"])]
+    #[test_case(Pe | Zs | Ll | Cn, &["Cn", "Ll", "Pe", "Zs"])]
+    fn test_set_to_abbr_vec(set: UnicodeCategorySet, expected: &[&str]) {
+        assert_eq!(set.to_abbr_vec(), expected);
+    }
+
     #[test]
     fn test_set_add() {
         let mut set = UnicodeCategorySet::new();
@@ -557,13 +1063,76 @@ mod tests {
         assert!(!set.contains(Ll));
     }
 
+    #[test_case(&[], UnicodeCategorySet::new())]
+    #[test_case(&[Lu], Lu.into())]
+    #[test_case(&[Lu, Ll, Lu], Lu | Ll)]
+    #[test_case(&[Ll, Lm, Lo, Lt, Lu], UnicodeCategory::L)]
+    fn test_set_from_slice(categories: &[UnicodeCategory], expected: UnicodeCategorySet) {
+        assert_eq!(UnicodeCategorySet::from_slice(categories), expected);
+    }
+
+    #[test]
+    fn test_set_add_remove() {
+        let mut set = UnicodeCategorySet::new();
+        set.add(Ll);
+        set.remove(Ll);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_toggle() {
+        let mut set = UnicodeCategorySet::new();
+        set.toggle(Ll);
+        assert!(set.contains(Ll));
+        assert_eq!(set.len(), 1);
+        set.toggle(Ll);
+        assert!(set.is_empty());
+    }
+
+    #[test_case(UnicodeCategorySet::new(), UnicodeCategorySet::new(), true, true, true)]
+    #[test_case(
+        UnicodeCategorySet::new(),
+        UnicodeCategorySet::all(),
+        true,
+        false,
+        true
+    )]
+    #[test_case(
+        UnicodeCategorySet::all(),
+        UnicodeCategorySet::new(),
+        false,
+        true,
+        true
+    )]
+    #[test_case(
+        UnicodeCategorySet::all(),
+        UnicodeCategorySet::all(),
+        true,
+        true,
+        false
+    )]
+    #[test_case(Lu | Ll, UnicodeCategory::L, true, false, false)]
+    #[test_case(UnicodeCategory::L, UnicodeCategory::N, false, false, true)]
+    #[test_case(Lu | Nd, UnicodeCategory::L, false, false, false)]
+    fn test_set_relations(
+        set: UnicodeCategorySet,
+        other: UnicodeCategorySet,
+        subset: bool,
+        superset: bool,
+        disjoint: bool,
+    ) {
+        assert_eq!(set.is_subset(other), subset);
+        assert_eq!(set.is_superset(other), superset);
+        assert_eq!(set.is_disjoint(other), disjoint);
+    }
+
     #[test]
     #[allow(clippy::clone_on_copy)]
     fn test_category_set_traits() {
         let set = UnicodeCategory::L;
         let mut hasher = DefaultHasher::new();
         set.hash(&mut hasher);
-        hasher.finish();
+        let _ = hasher.finish();
         let _ = set.clone();
         assert_eq!(format!("{set:?}"), "UnicodeCategorySet(671371264)");
     }
@@ -591,12 +1160,145 @@ mod tests {
         assert_eq!(set, Ll | Cs | Cc | Cf | Cn | Co);
     }
 
+    #[test]
+    fn test_bit_and() {
+        assert_eq!(
+            UnicodeCategory::L & UnicodeCategorySet::new(),
+            UnicodeCategorySet::new()
+        );
+        assert_eq!(UnicodeCategory::L & (Lu | Nd), Lu.into());
+        assert_eq!(UnicodeCategory::L & Lu, Lu.into());
+        assert_eq!(UnicodeCategory::L & Nd, UnicodeCategorySet::new());
+        let mut set = UnicodeCategory::L | UnicodeCategory::N;
+        set &= UnicodeCategory::N | Lu;
+        assert_eq!(set, UnicodeCategory::N | Lu);
+        set &= Nd;
+        assert_eq!(set, Nd.into());
+        const INTERSECTION: UnicodeCategorySet =
+            UnicodeCategory::L.intersection(UnicodeCategory::C);
+        assert!(INTERSECTION.is_empty());
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(UnicodeCategory::L - Lm.into(), Ll | Lo | Lt | Lu);
+        assert_eq!(UnicodeCategory::L - UnicodeCategory::N, UnicodeCategory::L);
+        assert_eq!(
+            UnicodeCategory::L - UnicodeCategory::L,
+            UnicodeCategorySet::new()
+        );
+        let mut set = UnicodeCategory::L | UnicodeCategory::N;
+        set -= UnicodeCategory::L;
+        assert_eq!(set, UnicodeCategory::N);
+        set -= Nd.into();
+        assert_eq!(set, Nl | No);
+    }
+
+    #[test_case(UnicodeCategorySet::new(), UnicodeCategorySet::all())]
+    #[test_case(UnicodeCategory::L, UnicodeCategory::L)]
+    #[test_case(UnicodeCategory::L, Lu | Nd)]
+    #[test_case(UnicodeCategory::L | UnicodeCategory::P, UnicodeCategory::N | Po)]
+    // INVARIANT: `Sub` on category sets is a bitwise operation and can't overflow
+    #[allow(clippy::arithmetic_side_effects)]
+    fn test_set_named_operations(left: UnicodeCategorySet, right: UnicodeCategorySet) {
+        assert_eq!(left.union(right), left | right);
+        assert_eq!(left.intersection(right), left & right);
+        assert_eq!(left.difference(right), left - right);
+        assert_eq!(
+            left.symmetric_difference(right),
+            left.union(right).difference(left.intersection(right))
+        );
+        // De Morgan's laws
+        assert_eq!(
+            left.union(right).complement(),
+            left.complement().intersection(right.complement())
+        );
+        assert_eq!(
+            left.intersection(right).complement(),
+            left.complement().union(right.complement())
+        );
+        assert_eq!(
+            left.difference(right),
+            left.intersection(right.complement())
+        );
+    }
+
+    #[test_case(UnicodeCategorySet::new())]
+    #[test_case(UnicodeCategorySet::all())]
+    #[test_case(UnicodeCategory::L)]
+    #[test_case(Lu | Nd | Cn)]
+    fn test_complement(set: UnicodeCategorySet) {
+        assert_eq!(set | set.complement(), UnicodeCategorySet::all());
+        assert_eq!(set & set.complement(), UnicodeCategorySet::new());
+        assert_eq!(set.complement().complement(), set);
+        assert_eq!(
+            crate::query().exclude_categories(set).intervals(),
+            crate::query()
+                .include_categories(set.complement())
+                .intervals()
+        );
+    }
+
+    #[test]
+    fn test_set_from_iter() {
+        let set: UnicodeCategorySet = UnicodeCategorySet::all().iter().collect();
+        assert_eq!(set, UnicodeCategorySet::all());
+        let set: UnicodeCategorySet = vec![Lu, Ll, Lu].into_iter().collect();
+        assert_eq!(set, Lu | Ll);
+        let set: UnicodeCategorySet = core::iter::empty().collect();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_extend() {
+        let mut set = UnicodeCategorySet::from(Nd);
+        set.extend(vec![Lu, Ll]);
+        assert_eq!(set, Nd | Lu | Ll);
+        set.extend(vec![UnicodeCategory::Z, UnicodeCategory::N]);
+        assert_eq!(set, UnicodeCategory::N | UnicodeCategory::Z | Lu | Ll);
+    }
+
+    #[test]
+    fn test_set_into_iter() {
+        let set = Lu | Nd | Cc;
+        let mut categories = vec![];
+        for category in set {
+            categories.push(category);
+        }
+        assert_eq!(categories, set.iter().collect::<Vec<_>>());
+        let mut categories = vec![];
+        for category in &set {
+            categories.push(category);
+        }
+        assert_eq!(categories, set.iter().collect::<Vec<_>>());
+        assert_eq!(set.into_iter().len(), 3);
+    }
+
+    #[test]
+    fn test_set_iter_rev() {
+        let set = Pe | Nd | Lu;
+        assert_eq!(set.iter().rev().collect::<Vec<_>>(), vec![Lu, Nd, Pe]);
+        let mut forward: Vec<_> = UnicodeCategorySet::all().iter().rev().collect();
+        forward.reverse();
+        assert_eq!(
+            forward,
+            UnicodeCategorySet::all().iter().collect::<Vec<_>>()
+        );
+        let mut iter = set.iter();
+        assert_eq!(iter.next(), Some(Pe));
+        assert_eq!(iter.next_back(), Some(Lu));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(Nd));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
     #[test]
     fn test_set_iter() {
         let all_categories = UnicodeCategorySet::all();
         assert_eq!(all_categories.iter().len(), all_categories.len());
         let mut set = UnicodeCategorySet::new();
-        for category in all_categories.iter() {
+        for category in all_categories {
             let name = format!("{category}");
             assert_eq!(
                 UnicodeCategory::from_str(&name).expect("Invalid category"),
@@ -633,4 +1335,32 @@ mod tests {
     ) {
         assert_eq!(merge(include, exclude), expected);
     }
+
+    #[test_case(Some(Lu | Me | Cs | So), So.into(), Lu | Me | Cs)]
+    #[test_case(None, UnicodeCategory::L | UnicodeCategory::M | UnicodeCategory::N | UnicodeCategory::P | UnicodeCategory::S, UnicodeCategory::Z | UnicodeCategory::C)]
+    #[test_case(
+        Some(UnicodeCategorySet::new()),
+        UnicodeCategorySet::new(),
+        UnicodeCategorySet::new()
+    )]
+    #[test_case(None, UnicodeCategorySet::new(), UnicodeCategorySet::all())]
+    fn test_category_effective(
+        include: Option<UnicodeCategorySet>,
+        exclude: UnicodeCategorySet,
+        expected: UnicodeCategorySet,
+    ) {
+        assert_eq!(UnicodeCategorySet::effective(include, exclude), expected);
+    }
+
+    #[test_case(Lu | Nd, UnicodeCategory::L, Lu.into())]
+    #[test_case(Lu | Ll | Nd, UnicodeCategory::L, Lu | Ll)]
+    #[test_case(Nd.into(), UnicodeCategory::L, UnicodeCategorySet::new())]
+    #[test_case(UnicodeCategorySet::all(), UnicodeCategory::Z, UnicodeCategory::Z)]
+    fn test_set_exclusive_in_group(
+        set: UnicodeCategorySet,
+        group: UnicodeCategorySet,
+        expected: UnicodeCategorySet,
+    ) {
+        assert_eq!(set.exclusive_in_group(group), expected);
+    }
 }
@@ -1,2 +1,31 @@
+use crate::{Interval, UnicodeCategory, UnicodeVersion};
+
 pub(crate) const MAX_CODEPOINT: u32 = 0x0010_ffff;
 pub(crate) const ALL_CATEGORIES: u32 = 0x3fff_ffff;
+/// The number of Unicode categories.
+pub(crate) const CATEGORY_COUNT: usize = ALL_CATEGORIES.count_ones() as usize;
+pub(crate) const SURROGATES: (u32, u32) = (0xd800, 0xdfff);
+pub(crate) const MAX_BMP_CODEPOINT: u32 = 0xffff;
+/// Printable ASCII characters, from `!` to `~`.
+pub(crate) const PASSWORD_SAFE_RANGE: Interval = (0x21, 0x7e);
+/// Characters that are easy to confuse with each other.
+pub(crate) const AMBIGUOUS_CHARACTERS: &str = "0O1lI|";
+pub(crate) const ALL_VERSIONS: [UnicodeVersion; 8] = [
+    UnicodeVersion::V9_0_0,
+    UnicodeVersion::V10_0_0,
+    UnicodeVersion::V11_0_0,
+    UnicodeVersion::V12_0_0,
+    UnicodeVersion::V12_1_0,
+    UnicodeVersion::V13_0_0,
+    UnicodeVersion::V14_0_0,
+    UnicodeVersion::V15_0_0,
+];
+/// The number of supported Unicode versions.
+pub(crate) const VERSION_COUNT: usize = ALL_VERSIONS.len();
+
+// Caches are indexed by `UnicodeVersion as usize`, therefore every version needs a slot.
+// Adding a new version without updating `ALL_VERSIONS` fails to compile.
+const _: () = assert!(UnicodeVersion::latest() as usize + 1 == VERSION_COUNT);
+
+// Caches are indexed by `UnicodeCategory as usize` and `Lu` is the last category.
+const _: () = assert!(UnicodeCategory::Lu as usize + 1 == CATEGORY_COUNT);
@@ -13,6 +13,12 @@ pub enum Error {
     InvalidCodepoints(u32, u32),
     /// Codepoint is not in the allowed range.
     CodepointNotInRange(u32, u32),
+    /// Provided spec clause is invalid.
+    InvalidSpec(Box<str>),
+    /// Serialized intervals have invalid length.
+    InvalidBytesLength(usize),
+    /// Provided interval notation is invalid.
+    InvalidIntervals(Box<str>),
 }
 
 impl error::Error for Error {}
@@ -32,6 +38,15 @@ impl fmt::Display for Error {
             Error::CodepointNotInRange(minimum, maximum) => f.write_fmt(format_args!(
                 "Codepoints should be in [0; {MAX_CODEPOINT}] range. Got: [{minimum}; {maximum}]"
             )),
+            Error::InvalidSpec(clause) => {
+                f.write_fmt(format_args!("'{clause}' is not a valid spec clause"))
+            }
+            Error::InvalidBytesLength(length) => f.write_fmt(format_args!(
+                "Serialized intervals length should be a multiple of 8. Got {length}"
+            )),
+            Error::InvalidIntervals(value) => {
+                f.write_fmt(format_args!("'{value}' is not a valid interval"))
+            }
         }
     }
 }
@@ -12,11 +12,60 @@ pub fn from_str(string: &str) -> Vec<Interval> {
     intervals
 }
 
+/// Create a set of intervals for the given codepoints.
+#[inline]
+#[must_use]
+pub fn from_codepoints(codepoints: &[u32]) -> Vec<Interval> {
+    if codepoints.is_empty() {
+        return vec![];
+    }
+    let mut intervals: Vec<_> = codepoints
+        .iter()
+        .map(|codepoint| (*codepoint, *codepoint))
+        .collect();
+    merge(&mut intervals);
+    intervals
+}
+
+/// Count the number of codepoints in non-overlapping intervals.
+#[inline]
+#[must_use]
+pub fn count(intervals: &[Interval]) -> u32 {
+    intervals
+        .iter()
+        // INVARIANT: `right` is always `>= left` and the total number of codepoints is far below
+        // `u32::MAX`, hence no overflow
+        .map(
+            #[allow(clippy::arithmetic_side_effects)]
+            |(left, right)| right - left + 1,
+        )
+        .sum()
+}
+
+/// Count the number of codepoints in sorted non-overlapping intervals that are within the
+/// `[min, max]` range.
+#[inline]
+#[must_use]
+pub fn count_in(intervals: &[Interval], min: u32, max: u32) -> u32 {
+    let start = intervals.partition_point(|(_, right)| *right < min);
+    intervals[start..]
+        .iter()
+        .take_while(|(left, _)| *left <= max)
+        // INVARIANT: Every interval here intersects `[min, max]`, therefore the clipped
+        // right bound is not less than the clipped left one. The total size is limited by
+        // the number of Unicode codepoints, so the sum does not overflow
+        .map(
+            #[allow(clippy::arithmetic_side_effects)]
+            |(left, right)| (*right).min(max) - (*left).max(min) + 1,
+        )
+        .sum()
+}
+
 /// Subtract `right` set of intervals from `left`.
 #[inline]
 // Practically all interval values are < u32::MAX
 // Therefore there will be no panic (debug) / wrapping (release)
-#[allow(clippy::integer_arithmetic)]
+#[allow(clippy::arithmetic_side_effects)]
 #[must_use]
 pub fn subtract(mut left: Vec<Interval>, right: &[Interval]) -> Vec<Interval> {
     if right.is_empty() || left.is_empty() {
@@ -54,11 +103,78 @@ pub fn subtract(mut left: Vec<Interval>, right: &[Interval]) -> Vec<Interval> {
     }
 }
 
+/// Intersect two sorted sets of non-overlapping intervals.
+#[inline]
+#[must_use]
+pub fn intersect(left: &[Interval], right: &[Interval]) -> Vec<Interval> {
+    intersect_iter(left, right).collect()
+}
+
+/// Lazily intersect two sorted sets of non-overlapping intervals.
+#[inline]
+pub fn intersect_iter<'a>(
+    left: &'a [Interval],
+    right: &'a [Interval],
+) -> impl Iterator<Item = Interval> + 'a {
+    let (mut i, mut j) = (0, 0);
+    core::iter::from_fn(move || {
+        // INVARIANT: `i` and `j` are always less than the lengths of `left` and `right`,
+        // therefore they can't overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        while i < left.len() && j < right.len() {
+            let (ll, lr) = left[i];
+            let (rl, rr) = right[j];
+            // Advance the interval that ends first
+            if lr < rr {
+                i += 1;
+            } else {
+                j += 1;
+            }
+            let start = ll.max(rl);
+            let end = lr.min(rr);
+            if start <= end {
+                return Some((start, end));
+            }
+        }
+        None
+    })
+}
+
+/// Find pairs of overlapping intervals in arbitrary, possibly unsorted input.
+///
+/// Every pair contains indices of two intervals that share at least one codepoint, the smaller
+/// index goes first. Pairs are sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// let overlaps = unicode_intervals::find_overlaps(&[(10, 20), (0, 5), (15, 30), (5, 6)]);
+/// assert_eq!(overlaps, vec![(0, 2), (1, 3)]);
+/// ```
+#[must_use]
+pub fn find_overlaps(intervals: &[Interval]) -> Vec<(usize, usize)> {
+    let mut order: Vec<_> = (0..intervals.len()).collect();
+    order.sort_by_key(|idx| intervals[*idx].0);
+    let mut overlaps = vec![];
+    for (position, first) in order.iter().enumerate() {
+        let (_, right) = intervals[*first];
+        for second in order[position..]
+            .iter()
+            .skip(1)
+            .take_while(|idx| intervals[**idx].0 <= right)
+        {
+            overlaps.push(((*first).min(*second), (*first).max(*second)));
+        }
+    }
+    overlaps.sort_unstable();
+    overlaps
+}
+
 /// Merge intersecting intervals in-place.
 // Note, `#[inline]` leads to worse performance
 // Practically all interval values are < u32::MAX
 // Therefore there will be no panic (debug) / wrapping (release)
-#[allow(clippy::integer_arithmetic)]
+#[allow(clippy::arithmetic_side_effects)]
 pub fn merge(intervals: &mut Vec<Interval>) {
     #[allow(clippy::stable_sort_primitive)]
     intervals.sort_by_key(|a| a.0);
@@ -111,6 +227,17 @@ mod tests {
         assert_eq!(subtract(left, right), expected);
     }
 
+    #[test_case(&[], &[(1, 2)], &[]; "empty left")]
+    #[test_case(&[(1, 2)], &[], &[]; "empty right")]
+    #[test_case(&[(0, 10)], &[(2, 3), (9, 15)], &[(2, 3), (9, 10)]; "partial overlap")]
+    #[test_case(&[(0, 10)], &[(0, 10)], &[(0, 10)]; "full overlap")]
+    #[test_case(&[(0, 1), (5, 6)], &[(2, 4)], &[]; "disjoint")]
+    #[test_case(&[(0, 5), (7, 12)], &[(3, 8), (10, 20)], &[(3, 5), (7, 8), (10, 12)]; "interleaved")]
+    fn test_intersect(left: &[Interval], right: &[Interval], expected: &[Interval]) {
+        assert_eq!(intersect(left, right), expected);
+        assert_eq!(intersect(right, left), expected);
+    }
+
     #[test_case("", &[])]
     #[test_case("\u{10A07}", &[(68103, 68103)])]
     #[test_case("a", &[(97, 97)])]
@@ -120,4 +247,27 @@ mod tests {
     fn test_from_str(value: &str, expected: &[Interval]) {
         assert_eq!(from_str(value), expected);
     }
+
+    #[test_case(&[], &[]; "empty")]
+    #[test_case(&[(0, 1), (2, 3)], &[]; "adjacent")]
+    #[test_case(&[(10, 20), (0, 5), (15, 30), (5, 6)], &[(0, 2), (1, 3)]; "unsorted")]
+    #[test_case(&[(0, 10), (1, 2), (3, 4)], &[(0, 1), (0, 2)]; "nested")]
+    #[test_case(&[(1, 1), (1, 1), (1, 1)], &[(0, 1), (0, 2), (1, 2)]; "duplicates")]
+    fn test_find_overlaps(intervals: &[Interval], expected: &[(usize, usize)]) {
+        assert_eq!(find_overlaps(intervals), expected);
+    }
+
+    #[test_case(&[], 0)]
+    #[test_case(&[(97, 97)], 1)]
+    #[test_case(&[(0, 9), (20, 29)], 20)]
+    fn test_count(intervals: &[Interval], expected: u32) {
+        assert_eq!(count(intervals), expected);
+    }
+
+    #[test_case(&[], &[])]
+    #[test_case(&[97], &[(97, 97)])]
+    #[test_case(&[99, 97, 98, 97, 101], &[(97, 99), (101, 101)])]
+    fn test_from_codepoints(codepoints: &[u32], expected: &[Interval]) {
+        assert_eq!(from_codepoints(codepoints), expected);
+    }
 }
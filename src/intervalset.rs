@@ -1,9 +1,15 @@
-use crate::Interval;
+use crate::{
+    constants::{MAX_BMP_CODEPOINT, MAX_CODEPOINT, SURROGATES},
+    intervals, Error, Interval, UnicodeCategory, UnicodeCategorySet, UnicodeVersion,
+};
+use core::{fmt, iter::FusedIterator, ops::RangeInclusive, str::FromStr};
 
 /// A collection of non-overlapping Unicode codepoint intervals that enables interval-based
 /// operations, such as iteration over all Unicode codepoints or finding the codepoint at a
 /// specific position within the intervals.
-#[derive(Debug, Clone)]
+///
+/// Sets are compared and hashed by their codepoints.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntervalSet {
     intervals: Vec<Interval>,
     offsets: Vec<u32>,
@@ -17,7 +23,7 @@ impl IntervalSet {
         offsets.reserve_exact(intervals.len());
         let mut size = 0;
         // INVARIANT: `right` is always `>= left`, hence no overflow
-        #[allow(clippy::integer_arithmetic)]
+        #[allow(clippy::arithmetic_side_effects)]
         for (left, right) in &intervals {
             size += *right - *left + 1;
             offsets.push(size);
@@ -29,6 +35,175 @@ impl IntervalSet {
         }
     }
 
+    /// Creates an interval set with a single codepoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::single('A' as u32).expect("Invalid codepoint");
+    /// assert_eq!(interval_set.len(), 1);
+    /// assert!(interval_set.contains('A'));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `codepoint > 1114111`.
+    pub fn single(codepoint: u32) -> Result<IntervalSet, Error> {
+        if codepoint > MAX_CODEPOINT {
+            return Err(Error::CodepointNotInRange(codepoint, codepoint));
+        }
+        Ok(IntervalSet::new(vec![(codepoint, codepoint)]))
+    }
+
+    /// Serializes the interval set to bytes.
+    ///
+    /// Every interval is stored as two little-endian `u32` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['A', 'B']);
+    /// assert_eq!(interval_set.to_bytes(), vec![65, 0, 0, 0, 66, 0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.intervals.len().saturating_mul(8));
+        for (left, right) in &self.intervals {
+            bytes.extend_from_slice(&left.to_le_bytes());
+            bytes.extend_from_slice(&right.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserializes an interval set from bytes produced by `IntervalSet::to_bytes`.
+    ///
+    /// Unsorted or overlapping intervals are normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::from_bytes(&[65, 0, 0, 0, 66, 0, 0, 0])
+    ///     .expect("Invalid input");
+    /// assert_eq!(interval_set.len(), 2);
+    /// assert!(interval_set.contains('B'));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - The input length is not a multiple of 8
+    ///   - Interval's left bound is greater than its right bound
+    ///   - Codepoints are `> 1114111`
+    pub fn from_bytes(bytes: &[u8]) -> Result<IntervalSet, Error> {
+        let chunks = bytes.chunks_exact(8);
+        if !chunks.remainder().is_empty() {
+            return Err(Error::InvalidBytesLength(bytes.len()));
+        }
+        let mut intervals = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let left = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let right = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+            intervals.push((left, right));
+        }
+        IntervalSet::try_from_intervals(intervals)
+    }
+
+    /// Returns the smallest interval set covering all the given characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['e', 'a', 'c', 'b']);
+    /// assert_eq!(interval_set.len(), 4);
+    /// assert!(interval_set.contains('c'));
+    /// assert!(!interval_set.contains('d'));
+    /// ```
+    #[must_use]
+    pub fn cover_chars(chars: &[char]) -> IntervalSet {
+        chars.iter().copied().collect()
+    }
+
+    /// Creates an interval set from arbitrary intervals.
+    ///
+    /// Intervals may be unsorted, overlapping or adjacent, they are normalized before building
+    /// the set. Use [`IntervalSet::try_from_intervals`] for untrusted input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::from_intervals(vec![(97, 122), (65, 90), (70, 100)]);
+    /// assert_eq!(interval_set.intervals(), &[(65, 122)]);
+    /// assert_eq!(interval_set.len(), 58);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If any interval has its left border greater than its right border or contains
+    /// codepoints greater than `1114111`.
+    #[must_use]
+    pub fn from_intervals(intervals: Vec<Interval>) -> IntervalSet {
+        match IntervalSet::try_from_intervals(intervals) {
+            Ok(interval_set) => interval_set,
+            Err(error) => panic!("Invalid interval: {error}"),
+        }
+    }
+
+    /// Creates an interval set from arbitrary intervals or returns an error if any of them is
+    /// invalid.
+    ///
+    /// Intervals may be unsorted, overlapping or adjacent, they are normalized before building
+    /// the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::try_from_intervals(vec![(97, 122), (65, 90)])
+    ///     .expect("Invalid intervals");
+    /// assert_eq!(interval_set.len(), 52);
+    /// assert!(IntervalSet::try_from_intervals(vec![(90, 65)]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - Any interval has its left border greater than its right border
+    ///   - Any interval contains codepoints greater than `1114111`
+    pub fn try_from_intervals(mut intervals: Vec<Interval>) -> Result<IntervalSet, Error> {
+        for (left, right) in &intervals {
+            if *left > MAX_CODEPOINT || *right > MAX_CODEPOINT {
+                return Err(Error::CodepointNotInRange(*left, *right));
+            }
+            if left > right {
+                return Err(Error::InvalidCodepoints(*left, *right));
+            }
+        }
+        intervals::merge(&mut intervals);
+        Ok(IntervalSet::new(intervals))
+    }
+
+    /// Returns sorted, non-overlapping intervals of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.intervals(), &[(65, 90), (97, 122)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
     /// Returns the number of Unicode codepoints in the interval set.
     ///
     /// # Examples
@@ -47,6 +222,53 @@ impl IntervalSet {
         self.size as usize
     }
 
+    /// Returns the number of heap bytes allocated by the interval set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['a', 'c']);
+    /// assert!(interval_set.heap_size() >= 2 * 8 + 3 * 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn heap_size(&self) -> usize {
+        let intervals = self
+            .intervals
+            .capacity()
+            .saturating_mul(core::mem::size_of::<Interval>());
+        let offsets = self
+            .offsets
+            .capacity()
+            .saturating_mul(core::mem::size_of::<u32>());
+        intervals.saturating_add(offsets)
+    }
+
+    /// Returns the number of codepoints in the interval set that are valid `char` values.
+    ///
+    /// Unlike `len`, it does not count surrogate codepoints (`0xD800..=0xDFFF`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .max_codepoint(0xffff)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.len(), 65536);
+    /// assert_eq!(interval_set.char_len(), 63488);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn char_len(&self) -> usize {
+        let surrogates = intervals::count_in(&self.intervals, SURROGATES.0, SURROGATES.1);
+        // INVARIANT: Surrogates are a subset of the set's codepoints, hence no overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        let size = self.size - surrogates;
+        size as usize
+    }
+
     /// Returns `true` if the interval set contains no elements.
     ///
     /// # Examples
@@ -67,6 +289,201 @@ impl IntervalSet {
         self.size == 0
     }
 
+    /// Returns the number of codepoints in the longest run of consecutive codepoints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.longest_run(), 26);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn longest_run(&self) -> u32 {
+        self.intervals
+            .iter()
+            // INVARIANT: `right` is always `>= left`, hence no overflow
+            .map(
+                #[allow(clippy::arithmetic_side_effects)]
+                |(left, right)| right - left + 1,
+            )
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if all codepoints in the interval set are ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(interval_set.is_ascii());
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(!interval_set.is_ascii());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_ascii(&self) -> bool {
+        match self.intervals.last() {
+            Some((_, right)) => *right <= 127,
+            None => true,
+        }
+    }
+
+    /// Returns `true` if any codepoint in the interval set is outside the Basic Multilingual
+    /// Plane, i.e. requires a surrogate pair in UTF-16.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let ascii = IntervalSet::cover_chars(&['a', 'b']);
+    /// assert!(!ascii.crosses_bmp());
+    /// let emoji = IntervalSet::cover_chars(&['a', '😀']);
+    /// assert!(emoji.crosses_bmp());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn crosses_bmp(&self) -> bool {
+        match self.intervals.last() {
+            Some((_, right)) => *right > MAX_BMP_CODEPOINT,
+            None => false,
+        }
+    }
+
+    /// Splits the interval set into codepoints within the Basic Multilingual Plane and
+    /// codepoints above it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['a', '😀']);
+    /// let (bmp, supplementary) = interval_set.split_bmp();
+    /// assert!(bmp.contains('a'));
+    /// assert!(supplementary.contains('😀'));
+    /// ```
+    #[must_use]
+    pub fn split_bmp(&self) -> (IntervalSet, IntervalSet) {
+        let bmp = intervals::intersect(&self.intervals, &[(0, MAX_BMP_CODEPOINT)]);
+        let supplementary = intervals::subtract(self.intervals.clone(), &[(0, MAX_BMP_CODEPOINT)]);
+        (IntervalSet::new(bmp), IntervalSet::new(supplementary))
+    }
+
+    /// Returns the smallest interval containing all codepoints of the interval set.
+    ///
+    /// The returned interval may include codepoints that are not in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.bounding_interval(), Some(('A' as u32, 'Z' as u32)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bounding_interval(&self) -> Option<Interval> {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some((left, _)), Some((_, right))) => Some((*left, *right)),
+            _ => None,
+        }
+    }
+
+    /// Returns the smallest and the largest codepoints of the set or `None` if it is empty.
+    ///
+    /// Same as [`IntervalSet::bounding_interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.span(), Some(('A' as u32, 'Z' as u32)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Option<Interval> {
+        self.bounding_interval()
+    }
+
+    /// Returns the smallest codepoint of the set or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.first(), Some('A' as u32));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn first(&self) -> Option<u32> {
+        self.intervals.first().map(|(left, _)| *left)
+    }
+
+    /// Returns the largest codepoint of the set or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.last(), Some('Z' as u32));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn last(&self) -> Option<u32> {
+        self.intervals.last().map(|(_, right)| *right)
+    }
+
+    /// Returns `true` if both interval sets contain exactly the same codepoints.
+    ///
+    /// Interval sets do not track how they were built, therefore sets built for different
+    /// Unicode versions or queries are equal as long as their codepoints are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    /// let old = UnicodeVersion::V9_0_0.interval_set_for_category(UnicodeCategory::Zl);
+    /// let new = UnicodeVersion::V15_0_0.interval_set_for_category(UnicodeCategory::Zl);
+    /// assert!(old.same_codepoints(&new));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn same_codepoints(&self, other: &IntervalSet) -> bool {
+        self.intervals == other.intervals
+    }
+
     /// Returns `true` if the interval set contains a codepoint with the given value.
     ///
     /// # Examples
@@ -106,7 +523,7 @@ impl IntervalSet {
             return None;
         }
         // INVARIANT: There is a positive number of intervals at this point per the check above
-        #[allow(clippy::integer_arithmetic)]
+        #[allow(clippy::arithmetic_side_effects)]
         let mut current = self.intervals.len() - 1;
         if self.offsets[current] > index {
             let (mut high, mut low) = (current, 0_usize);
@@ -114,7 +531,7 @@ impl IntervalSet {
             //   - `low + 1` never overflows as all possible values are far below `u32::MAX`
             //   - `low + high` never overflows because two maximum values for these variables
             //     are far below `u32::MAX`
-            #[allow(clippy::integer_arithmetic)]
+            #[allow(clippy::arithmetic_side_effects)]
             while low + 1 < high {
                 let mid = (low + high) / 2;
                 if self.offsets[mid] <= index {
@@ -126,11 +543,11 @@ impl IntervalSet {
             current = low;
         }
         // INVARIANT: `index` & offsets are small enough and won't cause overflow
-        #[allow(clippy::integer_arithmetic)]
+        #[allow(clippy::arithmetic_side_effects)]
         Some(self.intervals[current].0 + index - self.offsets[current])
     }
 
-    /// Returns the index of a specific codepoint in the `IntervalSet`.
+    /// Returns the codepoint at `index` in the `IntervalSet` counting from the end.
     ///
     /// # Examples
     ///
@@ -138,35 +555,25 @@ impl IntervalSet {
     /// # use unicode_intervals::UnicodeCategory;
     /// let interval_set = unicode_intervals::query()
     ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(90)
     ///     .interval_set()
     ///     .expect("Invalid query input");
-    /// assert_eq!(interval_set.index_of('A'), Some(0));
-    /// assert_eq!(interval_set.index_of('c'), None);
+    /// assert_eq!(interval_set.codepoint_at_rev(0), Some('Z' as u32));
+    /// assert_eq!(interval_set.codepoint_at_rev(25), Some('A' as u32));
+    /// assert_eq!(interval_set.codepoint_at_rev(26), None);
     /// ```
     #[inline]
     #[must_use]
-    pub fn index_of(&self, codepoint: impl Into<u32>) -> Option<u32> {
-        let codepoint = codepoint.into();
-        for (offset, (left, right)) in self.offsets.iter().zip(self.intervals.iter()) {
-            if *left == codepoint {
-                return Some(*offset);
-            } else if *left > codepoint {
-                return None;
-            } else if codepoint <= *right {
-                // INVARIANT: `left` is smaller than `codepoint` and `offset` is small enough,
-                // so there is no overflow
-                #[allow(clippy::integer_arithmetic)]
-                return Some(*offset + (codepoint - left));
-            }
+    pub fn codepoint_at_rev(&self, index: u32) -> Option<u32> {
+        if index >= self.size {
+            return None;
         }
-        None
+        // INVARIANT: `index < size` per the check above, hence no overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        self.codepoint_at(self.size - 1 - index)
     }
 
-    /// Returns the index of a specific codepoint in the `IntervalSet` if it is present in the set,
-    /// or the index of the closest codepoint that is greater than the given one.
-    ///
-    /// If the given codepoint is greater than the largest codepoint in the set, then the set's
-    /// size is returned.
+    /// Returns a new set with the first `n` codepoints of this set.
     ///
     /// # Examples
     ///
@@ -176,26 +583,30 @@ impl IntervalSet {
     ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
     ///     .interval_set()
     ///     .expect("Invalid query input");
-    /// assert_eq!(interval_set.index_above('Z'), 25);
+    /// let first = interval_set.take(3);
+    /// assert_eq!(first.len(), 3);
+    /// assert!(first.contains('C'));
+    /// assert!(!first.contains('D'));
     /// ```
-    #[inline]
     #[must_use]
-    pub fn index_above(&self, codepoint: impl Into<u32>) -> u32 {
-        let codepoint = codepoint.into();
-        for (offset, (left, right)) in self.offsets.iter().zip(self.intervals.iter()) {
-            if *left >= codepoint {
-                return *offset;
-            } else if codepoint <= *right {
-                // INVARIANT: `left` is smaller than `codepoint` and `offset` is small enough,
-                // so there is no overflow
-                #[allow(clippy::integer_arithmetic)]
-                return *offset + (codepoint - left);
-            }
+    pub fn take(&self, n: u32) -> IntervalSet {
+        if n >= self.size {
+            return self.clone();
         }
-        self.size
+        let last = match n.checked_sub(1).and_then(|index| self.codepoint_at(index)) {
+            Some(last) => last,
+            None => return IntervalSet::new(vec![]),
+        };
+        let intervals = self
+            .intervals
+            .iter()
+            .take_while(|(left, _)| *left <= last)
+            .map(|(left, right)| (*left, (*right).min(last)))
+            .collect();
+        IntervalSet::new(intervals)
     }
 
-    /// Returns an iterator over all codepoints in all contained intervals.
+    /// Returns a new set without the first `n` codepoints of this set.
     ///
     /// # Examples
     ///
@@ -203,7 +614,230 @@ impl IntervalSet {
     /// # use unicode_intervals::UnicodeCategory;
     /// let interval_set = unicode_intervals::query()
     ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
-    ///     .max_codepoint(67)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let rest = interval_set.skip(25);
+    /// assert_eq!(rest.len(), 1);
+    /// assert!(rest.contains('Z'));
+    /// ```
+    #[must_use]
+    pub fn skip(&self, n: u32) -> IntervalSet {
+        let Some(first) = self.codepoint_at(n) else {
+            return IntervalSet::new(vec![]);
+        };
+        let start = self.intervals.partition_point(|(_, right)| *right < first);
+        let intervals = self.intervals[start..]
+            .iter()
+            .map(|(left, right)| ((*left).max(first), *right))
+            .collect();
+        IntervalSet::new(intervals)
+    }
+
+    /// Returns the `k`-th of `of` parts of the set, where parts have roughly equal numbers of
+    /// codepoints.
+    ///
+    /// Parts do not overlap and together contain all codepoints of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let second = interval_set.partition(1, 2);
+    /// assert_eq!(second.len(), 13);
+    /// assert!(second.contains('N'));
+    /// assert!(!second.contains('M'));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `k >= of`.
+    #[must_use]
+    pub fn partition(&self, k: usize, of: usize) -> IntervalSet {
+        assert!(k < of, "`k` should be less than `of`");
+        let size = u128::from(self.size);
+        // INVARIANTS:
+        //   - `k < of`, hence `k + 1` does not overflow and `of` is not zero
+        //   - `size` is at most `1114112` and fits into `u128` multiplied by any `usize`
+        //   - `start <= end <= size`, hence both fit into `u32`
+        #[allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
+        let (start, end) = (
+            (size * k as u128 / of as u128) as u32,
+            (size * (k + 1) as u128 / of as u128) as u32,
+        );
+        // INVARIANT: `start <= end`, see above
+        #[allow(clippy::arithmetic_side_effects)]
+        self.skip(start).take(end - start)
+    }
+
+    /// Returns the index of a specific codepoint in the `IntervalSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.index_of('A'), Some(0));
+    /// assert_eq!(interval_set.index_of('c'), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn index_of(&self, codepoint: impl Into<u32>) -> Option<u32> {
+        let codepoint = codepoint.into();
+        // The first interval that ends at or after `codepoint`
+        let idx = self
+            .intervals
+            .partition_point(|(_, right)| *right < codepoint);
+        let (left, _) = self.intervals.get(idx)?;
+        if codepoint < *left {
+            return None;
+        }
+        // INVARIANT: `left` is not greater than `codepoint` and `offset` is small enough,
+        // so there is no overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        Some(self.offsets[idx] + (codepoint - left))
+    }
+
+    /// Returns a uniformly random codepoint from the set or `None` if the set is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let codepoint = interval_set.sample(&mut rand::thread_rng()).expect("Set is not empty");
+    /// assert!(interval_set.contains(codepoint));
+    /// ```
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<u32> {
+        if self.size == 0 {
+            return None;
+        }
+        self.codepoint_at(rng.gen_range(0..self.size))
+    }
+
+    /// Returns a uniformly random character from the set or `None` if the set has no codepoints
+    /// that are valid `char` values.
+    ///
+    /// Surrogates are never returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .min_codepoint(0xd7ff)
+    ///     .max_codepoint(0xe000)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let c = interval_set.sample_char(&mut rand::thread_rng()).expect("Set has characters");
+    /// assert!(c == '\u{d7ff}' || c == '\u{e000}');
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn sample_char<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<char> {
+        let surrogates = intervals::count_in(&self.intervals, SURROGATES.0, SURROGATES.1);
+        let below_surrogates = self.index_above(SURROGATES.0);
+        // INVARIANT: Surrogates are a subset of the set's codepoints, hence no overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        let size = self.size - surrogates;
+        if size == 0 {
+            return None;
+        }
+        let mut index = rng.gen_range(0..size);
+        if index >= below_surrogates {
+            // INVARIANT: `index` is less than the number of non-surrogate codepoints,
+            // therefore skipping surrogates stays within the set's size
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                index += surrogates;
+            }
+        }
+        self.codepoint_at(index).and_then(char::from_u32)
+    }
+
+    /// Returns a random string of `len` characters drawn uniformly from the set.
+    ///
+    /// The length is measured in characters, not bytes. Surrogates are never used and if the
+    /// set has no valid characters, then the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let string = interval_set.sample_string(&mut rand::thread_rng(), 10);
+    /// assert_eq!(string.chars().count(), 10);
+    /// assert!(string.chars().all(char::is_alphabetic));
+    /// ```
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn sample_string<R: rand::Rng + ?Sized>(&self, rng: &mut R, len: usize) -> String {
+        let mut string = String::with_capacity(len);
+        for _ in 0..len {
+            match self.sample_char(rng) {
+                Some(c) => string.push(c),
+                None => break,
+            }
+        }
+        string
+    }
+
+    /// Returns the index of a specific codepoint in the `IntervalSet` if it is present in the set,
+    /// or the index of the closest codepoint that is greater than the given one.
+    ///
+    /// If the given codepoint is greater than the largest codepoint in the set, then the set's
+    /// size is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.index_above('Z'), 25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn index_above(&self, codepoint: impl Into<u32>) -> u32 {
+        let codepoint = codepoint.into();
+        for (offset, (left, right)) in self.offsets.iter().zip(self.intervals.iter()) {
+            if *left >= codepoint {
+                return *offset;
+            } else if codepoint <= *right {
+                // INVARIANT: `left` is smaller than `codepoint` and `offset` is small enough,
+                // so there is no overflow
+                #[allow(clippy::arithmetic_side_effects)]
+                return *offset + (codepoint - left);
+            }
+        }
+        self.size
+    }
+
+    /// Returns an iterator over all codepoints in all contained intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(67)
     ///     .interval_set()
     ///     .expect("Invalid query input");
     /// let mut iterator = interval_set.iter();
@@ -212,17 +846,789 @@ impl IntervalSet {
     /// assert_eq!(iterator.next(), Some('C' as u32));
     /// assert_eq!(iterator.next(), None);
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = u32> + DoubleEndedIterator + '_ {
-        self.intervals
+    pub fn iter(&self) -> Codepoints<'_> {
+        Codepoints::new(&self.intervals, self.size)
+    }
+
+    /// Returns an iterator over all characters in all contained intervals.
+    ///
+    /// Surrogates (`U+D800..=U+DFFF`) are not valid `char` values and are silently skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(67)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let chars: String = interval_set.chars().collect();
+    /// assert_eq!(chars, "ABC");
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.iter().filter_map(char::from_u32)
+    }
+
+    /// Returns an iterator over `(index, codepoint)` pairs, where `index` is the same as
+    /// `index_of` returns for `codepoint`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let mut iterator = interval_set.enumerate_codepoints();
+    /// assert_eq!(iterator.next(), Some((0, 'A' as u32)));
+    /// assert_eq!(iterator.next(), Some((1, 'B' as u32)));
+    /// ```
+    pub fn enumerate_codepoints(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (0..self.size).zip(self.iter())
+    }
+
+    /// Returns an iterator over intervals of the set from the highest to the lowest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let mut iterator = interval_set.intervals_rev();
+    /// assert_eq!(iterator.next(), Some((65, 90)));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn intervals_rev(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().rev().copied()
+    }
+
+    /// Returns an iterator over codepoints in the set that are within the `[min, max]` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let codepoints: Vec<_> = interval_set.iter_range('X' as u32, 'b' as u32).collect();
+    /// assert_eq!(codepoints, &['X' as u32, 'Y' as u32, 'Z' as u32]);
+    /// ```
+    pub fn iter_range(&self, min: u32, max: u32) -> impl Iterator<Item = u32> + '_ {
+        let start = self.intervals.partition_point(|(_, right)| *right < min);
+        self.intervals[start..]
+            .iter()
+            .take_while(move |(left, _)| *left <= max)
+            .flat_map(move |(left, right)| (*left).max(min)..=(*right).min(max))
+    }
+
+    /// Returns an iterator over every `step`-th codepoint of the set, starting from the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(90)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let codepoints: Vec<_> = interval_set.iter_step(10).collect();
+    /// assert_eq!(codepoints, &['A' as u32, 'K' as u32, 'U' as u32]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `step` is zero.
+    pub fn iter_step(&self, step: u32) -> impl Iterator<Item = u32> + '_ {
+        assert!(step >= 1, "`step` should be at least 1");
+        (0..self.size)
+            .step_by(step as usize)
+            .filter_map(move |index| self.codepoint_at(index))
+    }
+
+    /// Returns a new set with every codepoint of this set transformed by `f`.
+    ///
+    /// Transformed codepoints that are greater than `1114111` are skipped.
+    /// The transformation is applied to each codepoint separately, therefore the complexity
+    /// is `O(len())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['a', 'b', 'c']);
+    /// let uppercase = interval_set.map_codepoints(|codepoint| codepoint - 32);
+    /// assert!(uppercase.contains('A'));
+    /// assert!(!uppercase.contains('a'));
+    /// ```
+    #[must_use]
+    pub fn map_codepoints(&self, f: impl Fn(u32) -> u32) -> IntervalSet {
+        let mut intervals: Vec<_> = self
+            .iter()
+            .map(f)
+            .filter(|codepoint| *codepoint <= MAX_CODEPOINT)
+            .map(|codepoint| (codepoint, codepoint))
+            .collect();
+        intervals::merge(&mut intervals);
+        IntervalSet::new(intervals)
+    }
+
+    /// Returns an iterator over all characters in the set that satisfy the predicate.
+    ///
+    /// Surrogate codepoints are not valid characters and are always skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let vowels: String = interval_set.filter(|c| "aeiou".contains(c)).collect();
+    /// assert_eq!(vowels, "aeiou");
+    /// ```
+    pub fn filter<'a>(
+        &'a self,
+        predicate: impl Fn(char) -> bool + 'a,
+    ) -> impl Iterator<Item = char> + 'a {
+        self.iter()
+            .filter_map(char::from_u32)
+            .filter(move |c| predicate(*c))
+    }
+
+    /// Returns the number of intervals that intersect the `[min, max]` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.intervals_overlapping(0, 127), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intervals_overlapping(&self, min: u32, max: u32) -> usize {
+        // Intervals are sorted and non-overlapping, therefore both bounds could be found via
+        // binary search
+        let start = self.intervals.partition_point(|(_, right)| *right < min);
+        let end = self.intervals.partition_point(|(left, _)| *left <= max);
+        end.saturating_sub(start)
+    }
+
+    /// Returns a regular expression character class matching codepoints of the set.
+    ///
+    /// Every codepoint is written as a `\x{...}` escape, therefore the class is valid regardless
+    /// of the characters it contains. Surrogates can not be matched by regular expressions and
+    /// are skipped. If nothing is left, then the returned class matches nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set: IntervalSet = "41-5A,2603".parse().expect("Invalid intervals");
+    /// assert_eq!(interval_set.to_regex_class(), r"[\x{41}-\x{5A}\x{2603}]");
+    /// ```
+    #[must_use]
+    pub fn to_regex_class(&self) -> String {
+        let intervals = intervals::subtract(self.intervals.clone(), &[SURROGATES]);
+        if intervals.is_empty() {
+            return format!(r"[^\x{{0}}-\x{{{MAX_CODEPOINT:X}}}]");
+        }
+        let mut class = String::from("[");
+        for (left, right) in intervals {
+            if left == right {
+                class.push_str(&format!(r"\x{{{left:X}}}"));
+            } else {
+                class.push_str(&format!(r"\x{{{left:X}}}-\x{{{right:X}}}"));
+            }
+        }
+        class.push(']');
+        class
+    }
+
+    /// Returns the UTF-8 encoded boundaries of every interval in the set.
+    ///
+    /// Surrogate codepoints (`U+D800..=U+DFFF`) have no UTF-8 encoding, therefore they are
+    /// removed before encoding. Intervals spanning the surrogate range are split into two parts
+    /// and intervals consisting only of surrogates are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(200)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let encoded = interval_set.encode_intervals_utf8();
+    /// assert_eq!(encoded[0], ("A".to_string(), "Z".to_string()));
+    /// assert_eq!(encoded[1], ("À".to_string(), "È".to_string()));
+    /// ```
+    #[must_use]
+    pub fn encode_intervals_utf8(&self) -> Vec<(String, String)> {
+        intervals::subtract(self.intervals.clone(), &[SURROGATES])
+            .into_iter()
+            .filter_map(|(left, right)| {
+                Some((
+                    char::from_u32(left)?.to_string(),
+                    char::from_u32(right)?.to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns a new set with only the codepoints that belong to `category` in the given
+    /// Unicode version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    /// let interval_set = unicode_intervals::query()
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let uppercase = interval_set.filter_category(UnicodeVersion::V15_0_0, UnicodeCategory::Lu);
+    /// assert_eq!(uppercase.len(), 26);
+    /// ```
+    #[must_use]
+    pub fn filter_category(
+        &self,
+        version: UnicodeVersion,
+        category: UnicodeCategory,
+    ) -> IntervalSet {
+        IntervalSet::new(intervals::intersect(
+            &self.intervals,
+            version.intervals_for(category),
+        ))
+    }
+
+    /// Returns a new set with only the codepoints within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let ascii = interval_set.intersect_range(0..=127);
+    /// assert_eq!(ascii.len(), 52);
+    /// ```
+    #[must_use]
+    pub fn intersect_range(&self, range: RangeInclusive<u32>) -> IntervalSet {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return IntervalSet::new(vec![]);
+        }
+        IntervalSet::new(intervals::intersect(&self.intervals, &[(start, end)]))
+    }
+
+    /// Returns an interval set with codepoints that are present in either of sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let uppercase = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let lowercase = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::LOWERCASE_LETTER)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let letters = uppercase.union(&lowercase);
+    /// assert_eq!(letters.len(), 52);
+    /// assert_eq!(letters.codepoint_at(26), Some('a' as u32));
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = self.intervals.clone();
+        intervals.extend_from_slice(&other.intervals);
+        if !intervals.is_empty() {
+            intervals::merge(&mut intervals);
+        }
+        IntervalSet::new(intervals)
+    }
+
+    /// Returns an interval set with codepoints that are present in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let punctuation = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::P)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let ascii = unicode_intervals::query()
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let ascii_punctuation = punctuation.intersection(&ascii);
+    /// assert_eq!(ascii_punctuation.len(), 23);
+    /// assert_eq!(ascii_punctuation.codepoint_at(0), Some('!' as u32));
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::new(intervals::intersect(&self.intervals, &other.intervals))
+    }
+
+    /// Returns an interval set with codepoints of this set that are not present in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{IntervalSet, UnicodeCategory};
+    /// let letters = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let blocklist = IntervalSet::cover_chars(&['a', 'b', 'z']);
+    /// let allowed = letters.difference(&blocklist);
+    /// assert_eq!(allowed.len(), 49);
+    /// assert!(!allowed.contains('b'));
+    /// assert!(allowed.contains('c'));
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::new(intervals::subtract(
+            self.intervals.clone(),
+            &other.intervals,
+        ))
+    }
+
+    /// Returns an interval set with codepoints that are present in exactly one of sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let left = unicode_intervals::query()
+    ///     .min_codepoint('a' as u32)
+    ///     .max_codepoint('f' as u32)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let right = unicode_intervals::query()
+    ///     .min_codepoint('d' as u32)
+    ///     .max_codepoint('k' as u32)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let difference = left.symmetric_difference(&right);
+    /// assert_eq!(difference.len(), 8);
+    /// assert!(difference.contains('c'));
+    /// assert!(!difference.contains('e'));
+    /// assert!(difference.contains('g'));
+    /// ```
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = intervals::subtract(self.intervals.clone(), &other.intervals);
+        intervals.extend(intervals::subtract(
+            other.intervals.clone(),
+            &self.intervals,
+        ));
+        if !intervals.is_empty() {
+            intervals::merge(&mut intervals);
+        }
+        IntervalSet::new(intervals)
+    }
+
+    /// Returns an iterator over intervals of this set clipped to intervals of `mask`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let letters = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let ascii = unicode_intervals::query()
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let clipped: Vec<_> = letters.iter_intervals_within(&ascii).collect();
+    /// assert_eq!(clipped, &[(65, 90), (97, 122)]);
+    /// ```
+    pub fn iter_intervals_within<'a>(
+        &'a self,
+        mask: &'a IntervalSet,
+    ) -> impl Iterator<Item = Interval> + 'a {
+        intervals::intersect_iter(&self.intervals, &mask.intervals)
+    }
+
+    /// Splits the set into non-empty parts by Unicode category in the given version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    /// let interval_set = unicode_intervals::query()
+    ///     .max_codepoint(127)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let parts = interval_set.partition_by_category(UnicodeVersion::V15_0_0);
+    /// let (category, uppercase) = &parts[parts.len() - 1];
+    /// assert_eq!(*category, UnicodeCategory::Lu);
+    /// assert_eq!(uppercase.len(), 26);
+    /// ```
+    #[must_use]
+    pub fn partition_by_category(
+        &self,
+        version: UnicodeVersion,
+    ) -> Vec<(UnicodeCategory, IntervalSet)> {
+        UnicodeCategorySet::all()
             .iter()
-            .flat_map(|(left, right)| *left..=*right)
+            .filter_map(|category| {
+                let part = self.filter_category(version, category);
+                (!part.is_empty()).then_some((category, part))
+            })
+            .collect()
+    }
+
+    /// Returns Unicode categories that have no codepoints in this set in the given version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// let absent = interval_set.absent_categories(UnicodeVersion::V15_0_0);
+    /// assert!(absent.contains(UnicodeCategory::Nd));
+    /// assert!(!absent.contains(UnicodeCategory::Lu));
+    /// ```
+    #[must_use]
+    pub fn absent_categories(&self, version: UnicodeVersion) -> UnicodeCategorySet {
+        let mut absent = UnicodeCategorySet::new();
+        for category in UnicodeCategorySet::all() {
+            if intervals::intersect_iter(&self.intervals, version.intervals_for(category))
+                .next()
+                .is_none()
+            {
+                absent.add(category);
+            }
+        }
+        absent
+    }
+
+    /// Returns the sizes of gaps between consecutive intervals within the `[min, max]` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// // Between `Z` and `a` there are 6 codepoints
+    /// assert_eq!(interval_set.gap_histogram(0, 122), vec![6]);
+    /// ```
+    #[must_use]
+    pub fn gap_histogram(&self, min: u32, max: u32) -> Vec<u32> {
+        let mut gaps = vec![];
+        for window in self.intervals.windows(2) {
+            let ((_, previous_right), (next_left, _)) = (window[0], window[1]);
+            // INVARIANT: Intervals are sorted and non-adjacent, therefore
+            // `previous_right + 1 < next_left` and there is no overflow
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                let start = (previous_right + 1).max(min);
+                let end = (next_left - 1).min(max);
+                if start <= end {
+                    gaps.push(end - start + 1);
+                }
+            }
+        }
+        gaps
+    }
+
+    /// Returns the number of codepoints in the set that are within the `[min, max]` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.count_in(0, 127), 52);
+    /// ```
+    #[must_use]
+    pub fn count_in(&self, min: u32, max: u32) -> u32 {
+        intervals::count_in(&self.intervals, min, max)
+    }
+
+    /// Returns the number of codepoints in each of the 17 Unicode planes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set = IntervalSet::cover_chars(&['a', 'b', '😀']);
+    /// let counts = interval_set.plane_counts();
+    /// assert_eq!(counts[0], 2);
+    /// assert_eq!(counts[1], 1);
+    /// ```
+    #[must_use]
+    pub fn plane_counts(&self) -> [u32; 17] {
+        let mut counts = [0; 17];
+        for (plane, count) in (0_u32..).zip(counts.iter_mut()) {
+            // INVARIANT: `plane` is at most 16, hence no overflow
+            #[allow(clippy::arithmetic_side_effects)]
+            let (min, max) = (plane << 16, (plane << 16) | 0xffff);
+            *count = intervals::count_in(&self.intervals, min, max);
+        }
+        counts
+    }
+
+    /// Returns the fraction of codepoints from the `[min, max]` range that are in the set.
+    ///
+    /// The crate does not ship Unicode block data, therefore coverage of a block is not available
+    /// directly. The block's boundaries have to be passed explicitly instead, e.g. `0..=127` for
+    /// "Basic Latin".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::L)
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(interval_set.coverage_of_range(65, 90), 1.0);
+    /// assert_eq!(interval_set.coverage_of_range(0, 127), 52.0 / 128.0);
+    /// ```
+    #[must_use]
+    pub fn coverage_of_range(&self, min: u32, max: u32) -> f64 {
+        if min > max {
+            return 0.0;
+        }
+        // INVARIANT: `min <= max`, therefore there is no overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        let width = f64::from(max - min) + 1.0;
+        f64::from(self.count_in(min, max)) / width
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = u32;
+    type IntoIter = Codepoints<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    /// Formats intervals as comma-separated hexadecimal ranges, e.g. `41-5A,61-7A,2603`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (left, right)) in self.intervals.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(",")?;
+            }
+            if left == right {
+                write!(f, "{left:X}")?;
+            } else {
+                write!(f, "{left:X}-{right:X}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IntervalSet {
+    type Err = Error;
+
+    /// Parses intervals in the same notation as produced by the `Display` implementation.
+    ///
+    /// Intervals may be unsorted or overlapping, they are normalized. An empty string produces
+    /// an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::IntervalSet;
+    /// let interval_set: IntervalSet = "61-7A,41-5A,2603".parse().expect("Invalid intervals");
+    /// assert_eq!(interval_set.len(), 53);
+    /// assert_eq!(interval_set.to_string(), "41-5A,61-7A,2603");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_codepoint(value: &str, interval: &str) -> Result<u32, Error> {
+            if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                return Err(Error::InvalidIntervals(
+                    interval.to_owned().into_boxed_str(),
+                ));
+            }
+            u32::from_str_radix(value, 16)
+                .map_err(|_| Error::InvalidIntervals(interval.to_owned().into_boxed_str()))
+        }
+
+        if s.trim().is_empty() {
+            return Ok(IntervalSet::new(vec![]));
+        }
+        let mut intervals = vec![];
+        for interval in s.split(',') {
+            let interval = interval.trim();
+            let (left, right) = match interval.split_once('-') {
+                Some((left, right)) => (
+                    parse_codepoint(left, interval)?,
+                    parse_codepoint(right, interval)?,
+                ),
+                None => {
+                    let codepoint = parse_codepoint(interval, interval)?;
+                    (codepoint, codepoint)
+                }
+            };
+            if left > MAX_CODEPOINT || right > MAX_CODEPOINT {
+                return Err(Error::CodepointNotInRange(left, right));
+            }
+            if left > right {
+                return Err(Error::InvalidCodepoints(left, right));
+            }
+            intervals.push((left, right));
+        }
+        intervals::merge(&mut intervals);
+        Ok(IntervalSet::new(intervals))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    /// Collects intervals into a set, normalizing them the same way as
+    /// [`IntervalSet::from_intervals`].
+    ///
+    /// # Panics
+    ///
+    /// If any interval is invalid.
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        IntervalSet::from_intervals(iter.into_iter().collect())
+    }
+}
+
+impl FromIterator<char> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut intervals: Vec<_> = iter
+            .into_iter()
+            .map(|c| (u32::from(c), u32::from(c)))
+            .collect();
+        intervals::merge(&mut intervals);
+        IntervalSet::new(intervals)
+    }
+}
+
+/// An iterator over codepoints of an [`IntervalSet`].
+///
+/// Created by [`IntervalSet::iter`].
+#[derive(Debug, Clone)]
+pub struct Codepoints<'a> {
+    /// Intervals that are not fully consumed yet.
+    intervals: &'a [Interval],
+    /// The next codepoint from the front, always within the first interval.
+    front: u32,
+    /// The next codepoint from the back, always within the last interval.
+    back: u32,
+    /// The number of codepoints left.
+    remaining: u32,
+}
+
+impl<'a> Codepoints<'a> {
+    fn new(intervals: &'a [Interval], size: u32) -> Codepoints<'a> {
+        Codepoints {
+            intervals,
+            front: intervals.first().map_or(0, |(left, _)| *left),
+            back: intervals.last().map_or(0, |(_, right)| *right),
+            remaining: size,
+        }
+    }
+}
+
+impl Iterator for Codepoints<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let codepoint = self.front;
+        let (first, rest) = self.intervals.split_first()?;
+        // INVARIANTS:
+        //   - `remaining` is not zero
+        //   - `front` is less than the right border of the first interval
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            self.remaining -= 1;
+            if codepoint == first.1 {
+                self.intervals = rest;
+                if let Some((left, _)) = rest.first() {
+                    self.front = *left;
+                }
+            } else {
+                self.front += 1;
+            }
+        }
+        Some(codepoint)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Codepoints<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let codepoint = self.back;
+        let (last, rest) = self.intervals.split_last()?;
+        // INVARIANTS:
+        //   - `remaining` is not zero
+        //   - `back` is greater than the left border of the last interval
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            self.remaining -= 1;
+            if codepoint == last.0 {
+                self.intervals = rest;
+                if let Some((_, right)) = rest.last() {
+                    self.back = *right;
+                }
+            } else {
+                self.back -= 1;
+            }
+        }
+        Some(codepoint)
     }
 }
 
+impl ExactSizeIterator for Codepoints<'_> {}
+
+impl FusedIterator for Codepoints<'_> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{UnicodeCategory, UnicodeVersion};
     use test_case::test_case;
 
     fn uppercase_letters() -> IntervalSet {
@@ -232,6 +1638,13 @@ mod tests {
             .expect("Invalid query input")
     }
 
+    fn letters() -> IntervalSet {
+        crate::query()
+            .include_categories(UnicodeCategory::L)
+            .interval_set()
+            .expect("Invalid query input")
+    }
+
     #[test_case(vec![(1, 1)])]
     #[test_case(vec![])]
     fn test_index_not_present(intervals: Vec<Interval>) {
@@ -251,12 +1664,91 @@ mod tests {
         assert_eq!(IntervalSet::new(intervals).index_above(index), expected);
     }
 
-    #[test_case('Z' as u32, 25; "In the set")]
-    #[test_case('b' as u32, 26; "Not in the set")]
-    #[test_case(125218, 1831; "Greater than all")]
-    fn test_index_above_with_uppercase_letters(codepoint: u32, expected: u32) {
-        let interval_set = uppercase_letters();
-        assert_eq!(interval_set.index_above(codepoint), expected);
+    #[test_case('Z' as u32, 25; "In the set")]
+    #[test_case('b' as u32, 26; "Not in the set")]
+    #[test_case(125218, 1831; "Greater than all")]
+    fn test_index_above_with_uppercase_letters(codepoint: u32, expected: u32) {
+        let interval_set = uppercase_letters();
+        assert_eq!(interval_set.index_above(codepoint), expected);
+    }
+
+    #[test]
+    fn test_longest_run() {
+        let interval_set = letters();
+        // CJK Unified Ideographs Extension B
+        assert_eq!(interval_set.longest_run(), 0x2a6df - 0x20000 + 1);
+        assert_eq!(IntervalSet::new(vec![]).longest_run(), 0);
+    }
+
+    #[test_case(vec![], true)]
+    #[test_case(vec![(0, 127)], true)]
+    #[test_case(vec![(0, 10), (127, 128)], false)]
+    fn test_is_ascii(intervals: Vec<Interval>, expected: bool) {
+        assert_eq!(IntervalSet::new(intervals).is_ascii(), expected);
+    }
+
+    #[test]
+    fn test_heap_size() {
+        let small = IntervalSet::new(vec![(0, 1)]);
+        let large = letters();
+        assert!(small.heap_size() >= 16);
+        assert!(large.heap_size() > small.heap_size());
+    }
+
+    #[test_case(UnicodeCategory::Zl, true)]
+    #[test_case(UnicodeCategory::Zp, true)]
+    #[test_case(UnicodeCategory::Lu, false)]
+    fn test_same_codepoints(category: UnicodeCategory, expected: bool) {
+        let old = UnicodeVersion::V9_0_0.interval_set_for_category(category);
+        let new = UnicodeVersion::V15_0_0.interval_set_for_category(category);
+        assert_eq!(old.same_codepoints(&new), expected);
+        assert!(new.same_codepoints(&new));
+    }
+
+    #[test_case(vec![], false)]
+    #[test_case(vec![(0, 0xffff)], false)]
+    #[test_case(vec![(0, 10), (0xfff0, 0x10000)], true)]
+    fn test_crosses_bmp(intervals: Vec<Interval>, expected: bool) {
+        assert_eq!(IntervalSet::new(intervals).crosses_bmp(), expected);
+    }
+
+    #[test_case(vec![(0, 10), (0xfff0, 0x10010), (0x20000, 0x20001)], &[(0, 10), (0xfff0, 0xffff)], &[(0x10000, 0x10010), (0x20000, 0x20001)])]
+    #[test_case(vec![(0, 0xffff)], &[(0, 0xffff)], &[])]
+    #[test_case(vec![(0x10000, 0x10ffff)], &[], &[(0x10000, 0x10ffff)])]
+    #[test_case(vec![], &[], &[])]
+    fn test_split_bmp(intervals: Vec<Interval>, bmp: &[Interval], supplementary: &[Interval]) {
+        let interval_set = IntervalSet::new(intervals);
+        let (left, right) = interval_set.split_bmp();
+        assert_eq!(left.intervals, bmp);
+        assert_eq!(right.intervals, supplementary);
+        assert_eq!(
+            left.len().checked_add(right.len()),
+            Some(interval_set.len())
+        );
+    }
+
+    #[test_case(vec![], 0)]
+    #[test_case(vec![(0, 10)], 11)]
+    #[test_case(vec![(0xd7ff, 0xe000)], 2)]
+    #[test_case(vec![(0xd800, 0xd800), (0xdfff, 0xdfff)], 0)]
+    fn test_char_len(intervals: Vec<Interval>, expected: usize) {
+        let interval_set = IntervalSet::new(intervals);
+        assert_eq!(interval_set.char_len(), expected);
+        assert!(interval_set.char_len() <= interval_set.len());
+    }
+
+    #[test]
+    fn test_bounding_interval() {
+        let interval_set = letters();
+        let (_, highest) = interval_set
+            .intervals
+            .last()
+            .expect("Letters are not empty");
+        assert_eq!(
+            interval_set.bounding_interval(),
+            Some(('A' as u32, *highest))
+        );
+        assert_eq!(IntervalSet::new(vec![]).bounding_interval(), None);
     }
 
     #[test_case('C', true)]
@@ -276,6 +1768,18 @@ mod tests {
         assert_eq!(interval_set.codepoint_at(index), expected);
     }
 
+    #[test]
+    fn test_codepoint_at_rev() {
+        let interval_set = uppercase_letters();
+        assert_eq!(
+            interval_set.codepoint_at_rev(0),
+            interval_set.iter().next_back()
+        );
+        assert_eq!(interval_set.codepoint_at_rev(1830), Some('A' as u32));
+        assert_eq!(interval_set.codepoint_at_rev(1831), None);
+        assert_eq!(IntervalSet::new(vec![]).codepoint_at_rev(0), None);
+    }
+
     #[test]
     fn test_codepoint_at_empty_set() {
         let interval_set = IntervalSet::new(vec![]);
@@ -319,6 +1823,786 @@ mod tests {
         assert_eq!(iter.next(), Some(125217));
     }
 
+    #[test_case(0, 127, 2; "ASCII")]
+    #[test_case(65, 65, 1; "Single codepoint")]
+    #[test_case(91, 96, 0; "Gap")]
+    #[test_case(127, 0, 0; "Inverted range")]
+    fn test_intervals_overlapping_letters(min: u32, max: u32, expected: usize) {
+        let interval_set = letters();
+        assert_eq!(interval_set.intervals_overlapping(min, max), expected);
+    }
+
+    #[test_case(vec![], 0, 100, 0)]
+    #[test_case(vec![(1, 5), (10, 15), (20, 25)], 5, 10, 2)]
+    #[test_case(vec![(1, 5), (10, 15), (20, 25)], 0, 100, 3)]
+    fn test_intervals_overlapping(intervals: Vec<Interval>, min: u32, max: u32, expected: usize) {
+        assert_eq!(
+            IntervalSet::new(intervals).intervals_overlapping(min, max),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_encode_intervals_utf8_ascii() {
+        let interval_set = uppercase_letters();
+        let (left, right) = &interval_set.encode_intervals_utf8()[0];
+        assert_eq!(left.as_bytes(), b"A");
+        assert_eq!(right.as_bytes(), b"Z");
+    }
+
+    #[test_case(vec![(0xd800, 0xdfff)], &[]; "Only surrogates")]
+    #[test_case(vec![(0xd7ff, 0xe000)], &[("\u{d7ff}", "\u{d7ff}"), ("\u{e000}", "\u{e000}")]; "Spanning surrogates")]
+    #[test_case(vec![(0xdc00, 0x10ffff)], &[("\u{e000}", "\u{10ffff}")]; "Starting in surrogates")]
+    fn test_encode_intervals_utf8_surrogates(intervals: Vec<Interval>, expected: &[(&str, &str)]) {
+        let encoded = IntervalSet::new(intervals).encode_intervals_utf8();
+        let encoded: Vec<_> = encoded
+            .iter()
+            .map(|(left, right)| (left.as_str(), right.as_str()))
+            .collect();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn test_filter_category() {
+        let interval_set = IntervalSet::new(vec![(0, 127)]);
+        let filtered = interval_set.filter_category(UnicodeVersion::V15_0_0, UnicodeCategory::Lu);
+        assert_eq!(filtered.intervals, &[(65, 90)]);
+        assert_eq!(filtered.len(), 26);
+        assert_eq!(filtered.index_of('B'), Some(1));
+    }
+
+    #[test]
+    fn test_iter_intervals_within() {
+        let interval_set = letters();
+        let mask = IntervalSet::new(vec![(0, 127)]);
+        let clipped: Vec<_> = interval_set.iter_intervals_within(&mask).collect();
+        assert_eq!(clipped, &[(65, 90), (97, 122)]);
+        let mask = IntervalSet::new(vec![(70, 72), (88, 100), (120, 170)]);
+        let clipped: Vec<_> = interval_set.iter_intervals_within(&mask).collect();
+        assert_eq!(
+            clipped,
+            &[(70, 72), (88, 90), (97, 100), (120, 122), (170, 170)]
+        );
+        let mask = IntervalSet::new(vec![]);
+        assert_eq!(interval_set.iter_intervals_within(&mask).count(), 0);
+    }
+
+    #[test]
+    fn test_partition_by_category() {
+        let interval_set = IntervalSet::new(vec![(0, 127)]);
+        let parts = interval_set.partition_by_category(UnicodeVersion::V15_0_0);
+        let categories: Vec<_> = parts.iter().map(|(category, _)| *category).collect();
+        assert_eq!(
+            categories,
+            &[
+                UnicodeCategory::Pe,
+                UnicodeCategory::Pc,
+                UnicodeCategory::Cc,
+                UnicodeCategory::Sc,
+                UnicodeCategory::Pd,
+                UnicodeCategory::Nd,
+                UnicodeCategory::Ll,
+                UnicodeCategory::Sm,
+                UnicodeCategory::Sk,
+                UnicodeCategory::Ps,
+                UnicodeCategory::Po,
+                UnicodeCategory::Zs,
+                UnicodeCategory::Lu,
+            ]
+        );
+        let total: usize = parts.iter().map(|(_, part)| part.len()).sum();
+        assert_eq!(total, interval_set.len());
+        for (category, part) in &parts {
+            assert_eq!(
+                part.intervals,
+                interval_set
+                    .filter_category(UnicodeVersion::V15_0_0, *category)
+                    .intervals
+            );
+        }
+    }
+
+    #[test_case(vec![], 0, 100, &[]; "Empty")]
+    #[test_case(vec![(1, 5), (10, 15)], 0, 100, &[4]; "Two intervals")]
+    #[test_case(vec![(1, 5), (10, 15)], 7, 100, &[3]; "Clipped from left")]
+    #[test_case(vec![(1, 5), (10, 15)], 0, 7, &[2]; "Clipped from right")]
+    #[test_case(vec![(1, 5), (10, 15), (20, 20)], 11, 100, &[4]; "Outside of range")]
+    fn test_gap_histogram(intervals: Vec<Interval>, min: u32, max: u32, expected: &[u32]) {
+        assert_eq!(
+            IntervalSet::new(intervals).gap_histogram(min, max),
+            expected
+        );
+    }
+
+    #[test_case(vec![], 0, 100, 0; "Empty")]
+    #[test_case(vec![(1, 5), (10, 15)], 0, 100, 11; "Whole set")]
+    #[test_case(vec![(1, 5), (10, 15)], 3, 11, 5; "Clipped")]
+    #[test_case(vec![(1, 5), (10, 15)], 6, 9, 0; "Gap")]
+    #[test_case(vec![(0, MAX_CODEPOINT)], 0, u32::MAX, MAX_CODEPOINT + 1; "Max range")]
+    fn test_count_in(intervals: Vec<Interval>, min: u32, max: u32, expected: u32) {
+        assert_eq!(IntervalSet::new(intervals).count_in(min, max), expected);
+    }
+
+    #[test_case(vec![(0, 127)], 0, 127, 1.0; "Full ASCII")]
+    #[test_case(vec![(0, 63)], 0, 127, 0.5; "Half ASCII")]
+    #[test_case(vec![], 0, 127, 0.0; "Empty")]
+    #[test_case(vec![(0, 127)], 127, 0, 0.0; "Inverted range")]
+    #[allow(clippy::float_cmp)]
+    fn test_coverage_of_range(intervals: Vec<Interval>, min: u32, max: u32, expected: f64) {
+        assert_eq!(
+            IntervalSet::new(intervals).coverage_of_range(min, max),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_single() {
+        let interval_set = IntervalSet::single('A' as u32).expect("Invalid codepoint");
+        assert_eq!(interval_set.len(), 1);
+        assert!(interval_set.contains('A'));
+        assert!(!interval_set.contains('B'));
+        assert!(IntervalSet::single(MAX_CODEPOINT).is_ok());
+        assert_eq!(
+            IntervalSet::single(MAX_CODEPOINT + 1)
+                .expect_err("Should fail")
+                .to_string(),
+            "Codepoints should be in [0; 1114111] range. Got: [1114112; 1114112]"
+        );
+    }
+
+    #[test_case(vec![]; "Empty")]
+    #[test_case(vec![(0, MAX_CODEPOINT)]; "Full range")]
+    #[test_case(vec![(65, 90), (97, 122), (9731, 9731)]; "Several intervals")]
+    fn test_bytes_roundtrip(intervals: Vec<Interval>) {
+        let interval_set = IntervalSet::new(intervals);
+        let bytes = interval_set.to_bytes();
+        assert_eq!(bytes.chunks_exact(8).len(), interval_set.intervals.len());
+        let restored = IntervalSet::from_bytes(&bytes).expect("Invalid input");
+        assert_eq!(restored.intervals, interval_set.intervals);
+        assert_eq!(restored.offsets, interval_set.offsets);
+        assert_eq!(restored.len(), interval_set.len());
+    }
+
+    #[test]
+    fn test_from_bytes_normalizes() {
+        let bytes = [10, 0, 0, 0, 20, 0, 0, 0, 1, 0, 0, 0, 15, 0, 0, 0];
+        let interval_set = IntervalSet::from_bytes(&bytes).expect("Invalid input");
+        assert_eq!(interval_set.intervals, &[(1, 20)]);
+    }
+
+    #[test_case(&[1, 0, 0, 0], "Serialized intervals length should be a multiple of 8. Got 4")]
+    #[test_case(&[1, 0, 0, 0, 2, 0, 0, 0, 3], "Serialized intervals length should be a multiple of 8. Got 9")]
+    #[test_case(&[2, 0, 0, 0, 1, 0, 0, 0], "Minimum codepoint should be less or equal than maximum codepoint. Got 2 < 1")]
+    #[test_case(&[0, 0, 0, 0, 0, 0, 17, 0], "Codepoints should be in [0; 1114111] range. Got: [0; 1114112]")]
+    fn test_from_bytes_error(bytes: &[u8], expected: &str) {
+        assert_eq!(
+            IntervalSet::from_bytes(bytes)
+                .expect_err("Should fail")
+                .to_string(),
+            expected
+        );
+    }
+
+    #[test_case(&[], &[])]
+    #[test_case(&['a', 'b', 'c', 'e'], &[(97, 99), (101, 101)])]
+    #[test_case(&['e', 'c', 'a', 'b', 'a'], &[(97, 99), (101, 101)])]
+    fn test_cover_chars(chars: &[char], expected: &[Interval]) {
+        assert_eq!(IntervalSet::cover_chars(chars).intervals, expected);
+    }
+
+    #[test_case(vec![(0, 9)], 2, &[0, 2, 4, 6, 8])]
+    #[test_case(vec![(0, 2), (5, 7)], 2, &[0, 2, 6])]
+    #[test_case(vec![(0, 9)], 100, &[0])]
+    #[test_case(vec![], 1, &[])]
+    fn test_iter_step(intervals: Vec<Interval>, step: u32, expected: &[u32]) {
+        let codepoints: Vec<_> = IntervalSet::new(intervals).iter_step(step).collect();
+        assert_eq!(codepoints, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "`step` should be at least 1")]
+    fn test_iter_step_zero() {
+        let _ = IntervalSet::new(vec![(0, 9)]).iter_step(0);
+    }
+
+    #[test]
+    fn test_absent_categories() {
+        let absent = uppercase_letters().absent_categories(UnicodeVersion::V15_0_0);
+        assert_eq!(absent.len(), 29);
+        assert!(absent.contains(UnicodeCategory::Nd));
+        assert!(absent.contains(UnicodeCategory::Sm));
+        assert!(!absent.contains(UnicodeCategory::Lu));
+        assert_eq!(
+            IntervalSet::new(vec![]).absent_categories(UnicodeVersion::V15_0_0),
+            UnicodeCategorySet::all()
+        );
+    }
+
+    #[test]
+    fn test_enumerate_codepoints() {
+        let interval_set = uppercase_letters();
+        assert_eq!(
+            interval_set.enumerate_codepoints().next(),
+            Some((0, 'A' as u32))
+        );
+        assert_eq!(
+            interval_set.enumerate_codepoints().count(),
+            interval_set.len()
+        );
+        for (index, codepoint) in interval_set.enumerate_codepoints() {
+            assert_eq!(interval_set.index_of(codepoint), Some(index));
+        }
+    }
+
+    #[test_case(vec![(65, 90)], 3, &[(65, 67)])]
+    #[test_case(vec![(0, 2), (5, 7)], 4, &[(0, 2), (5, 5)])]
+    #[test_case(vec![(0, 2), (5, 7)], 3, &[(0, 2)])]
+    #[test_case(vec![(0, 2), (5, 7)], 6, &[(0, 2), (5, 7)])]
+    #[test_case(vec![(0, 2), (5, 7)], 100, &[(0, 2), (5, 7)])]
+    #[test_case(vec![(0, 2)], 0, &[])]
+    #[test_case(vec![], 1, &[])]
+    fn test_take(intervals: Vec<Interval>, n: u32, expected: &[Interval]) {
+        let interval_set = IntervalSet::new(intervals).take(n);
+        assert_eq!(interval_set.intervals, expected);
+        assert_eq!(interval_set.len(), intervals::count(expected) as usize);
+    }
+
+    #[test_case(vec![(65, 90)], 25, &[(90, 90)])]
+    #[test_case(vec![(0, 2), (5, 7)], 2, &[(2, 2), (5, 7)])]
+    #[test_case(vec![(0, 2), (5, 7)], 3, &[(5, 7)])]
+    #[test_case(vec![(0, 2), (5, 7)], 0, &[(0, 2), (5, 7)])]
+    #[test_case(vec![(0, 2), (5, 7)], 6, &[])]
+    #[test_case(vec![], 0, &[])]
+    fn test_skip(intervals: Vec<Interval>, n: u32, expected: &[Interval]) {
+        let interval_set = IntervalSet::new(intervals).skip(n);
+        assert_eq!(interval_set.intervals, expected);
+        assert_eq!(interval_set.len(), intervals::count(expected) as usize);
+    }
+
+    #[test]
+    fn test_intersect_range() {
+        let expected = crate::query()
+            .include_categories(UnicodeCategory::L)
+            .max_codepoint(127)
+            .intervals()
+            .expect("Invalid query input");
+        let interval_set = letters();
+        assert_eq!(interval_set.intersect_range(0..=127).intervals, expected);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = interval_set.intersect_range(127..=0);
+        assert!(empty.is_empty());
+        assert_eq!(
+            interval_set.intersect_range(0..=u32::MAX).intervals,
+            interval_set.intervals
+        );
+    }
+
+    #[test_case(1)]
+    #[test_case(3)]
+    #[test_case(7)]
+    #[test_case(5000)]
+    fn test_partition(of: usize) {
+        let interval_set = uppercase_letters();
+        let mut codepoints = vec![];
+        for k in 0..of {
+            codepoints.extend(interval_set.partition(k, of).iter());
+        }
+        assert_eq!(codepoints, interval_set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "`k` should be less than `of`")]
+    fn test_partition_invalid() {
+        let _ = uppercase_letters().partition(2, 2);
+    }
+
+    #[test]
+    fn test_intervals_rev() {
+        let interval_set = uppercase_letters();
+        // Adlam capital letters
+        assert_eq!(
+            interval_set.intervals_rev().next(),
+            Some((0x1e900, 0x1e921))
+        );
+        assert_eq!(
+            interval_set.intervals_rev().next().as_ref(),
+            interval_set.intervals.last()
+        );
+        let mut intervals: Vec<_> = interval_set.intervals_rev().collect();
+        intervals.reverse();
+        assert_eq!(intervals, interval_set.intervals);
+    }
+
+    #[test]
+    fn test_plane_counts() {
+        let ascii = IntervalSet::new(vec![(0, 127)]).plane_counts();
+        assert_eq!(ascii[0], 128);
+        assert!(ascii[1..].iter().all(|count| *count == 0));
+        let emoji = IntervalSet::cover_chars(&['😀', '😁']).plane_counts();
+        assert_eq!(emoji[1], 2);
+        let all = IntervalSet::new(vec![(0, MAX_CODEPOINT)]).plane_counts();
+        assert!(all.iter().all(|count| *count == 0x10000));
+        let interval_set = letters();
+        assert_eq!(
+            interval_set.plane_counts().iter().sum::<u32>() as usize,
+            interval_set.len()
+        );
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let interval_set = uppercase_letters();
+        let codepoints: String = interval_set
+            .iter_range(70, 75)
+            .filter_map(char::from_u32)
+            .collect();
+        assert_eq!(codepoints, "FGHIJK");
+        assert_eq!(interval_set.iter_range(91, 96).count(), 0);
+        assert_eq!(interval_set.iter_range(75, 70).count(), 0);
+        assert_eq!(
+            interval_set.iter_range(0, u32::MAX).count(),
+            interval_set.len()
+        );
+    }
+
+    #[test_case(vec![(65, 67)], |codepoint| codepoint + 1, &[(66, 68)]; "Shift")]
+    #[test_case(vec![(65, 67), (70, 70)], |codepoint| codepoint / 2, &[(32, 33), (35, 35)]; "Merge")]
+    #[test_case(vec![(0, 5)], |codepoint| 5 - codepoint, &[(0, 5)]; "Reverse")]
+    #[test_case(vec![(MAX_CODEPOINT, MAX_CODEPOINT)], |codepoint| codepoint + 1, &[]; "Too large")]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn test_map_codepoints(intervals: Vec<Interval>, f: fn(u32) -> u32, expected: &[Interval]) {
+        let interval_set = IntervalSet::new(intervals).map_codepoints(f);
+        assert_eq!(interval_set.intervals, expected);
+    }
+
+    #[test]
+    fn test_filter() {
+        let interval_set = IntervalSet::new(vec![(0, 127)]);
+        let vowels: Vec<_> = interval_set.filter(|c| "aeiouAEIOU".contains(c)).collect();
+        assert_eq!(vowels, &['A', 'E', 'I', 'O', 'U', 'a', 'e', 'i', 'o', 'u']);
+    }
+
+    #[test]
+    fn test_filter_skips_surrogates() {
+        let interval_set = IntervalSet::new(vec![(0xd7ff, 0xe000)]);
+        let chars: Vec<_> = interval_set.filter(|_| true).collect();
+        assert_eq!(chars, &['\u{d7ff}', '\u{e000}']);
+    }
+
+    #[test]
+    fn test_union() {
+        let uppercase = uppercase_letters();
+        let lowercase = crate::query()
+            .include_categories(UnicodeCategory::LOWERCASE_LETTER)
+            .interval_set()
+            .expect("Invalid query input");
+        let union = uppercase.union(&lowercase);
+        // Categories are disjoint
+        assert_eq!(
+            Some(union.len()),
+            uppercase.len().checked_add(lowercase.len())
+        );
+        assert!(union.contains('A'));
+        assert!(union.contains('z'));
+        assert_eq!(union.index_of('a'), Some(26));
+        assert_eq!(union.codepoint_at(26), Some('a' as u32));
+    }
+
+    #[test_case(vec![(1, 5)], vec![(3, 10)], &[(1, 10)], 10; "overlapping")]
+    #[test_case(vec![(1, 5)], vec![(6, 10)], &[(1, 10)], 10; "adjacent")]
+    #[test_case(vec![(1, 5), (20, 30)], vec![(2, 3), (10, 12)], &[(1, 5), (10, 12), (20, 30)], 19; "nested")]
+    #[test_case(vec![], vec![(1, 2)], &[(1, 2)], 2; "empty left")]
+    #[test_case(vec![], vec![], &[], 0; "both empty")]
+    fn test_union_intervals(
+        left: Vec<Interval>,
+        right: Vec<Interval>,
+        expected: &[Interval],
+        len: usize,
+    ) {
+        let left = IntervalSet::new(left);
+        let right = IntervalSet::new(right);
+        for union in [left.union(&right), right.union(&left)] {
+            assert_eq!(union.intervals, expected);
+            assert_eq!(union.len(), len);
+        }
+    }
+
+    #[test_case(vec![(1, 5)], vec![(3, 10)], &[(3, 5)], 3; "partial overlap")]
+    #[test_case(vec![(1, 10)], vec![(3, 5)], &[(3, 5)], 3; "full overlap")]
+    #[test_case(vec![(1, 10), (20, 30)], vec![(5, 25)], &[(5, 10), (20, 25)], 12; "spanning")]
+    #[test_case(vec![(1, 5)], vec![(6, 10)], &[], 0; "disjoint")]
+    #[test_case(vec![], vec![(1, 2)], &[], 0; "empty")]
+    fn test_intersection(
+        left: Vec<Interval>,
+        right: Vec<Interval>,
+        expected: &[Interval],
+        len: usize,
+    ) {
+        let left = IntervalSet::new(left);
+        let right = IntervalSet::new(right);
+        for intersection in [left.intersection(&right), right.intersection(&left)] {
+            assert_eq!(intersection.intervals, expected);
+            assert_eq!(intersection.len(), len);
+        }
+    }
+
+    #[test]
+    fn test_intersection_index() {
+        let ascii = IntervalSet::new(vec![(0, 127)]);
+        let intersection = letters().intersection(&ascii);
+        assert_eq!(intersection.len(), 52);
+        assert_eq!(intersection.index_of('a'), Some(26));
+        assert_eq!(intersection.codepoint_at(51), Some('z' as u32));
+        assert!(letters()
+            .intersection(&uppercase_letters())
+            .same_codepoints(&uppercase_letters()));
+    }
+
+    #[test_case(vec![(1, 10)], vec![(3, 5)], &[(1, 2), (6, 10)]; "split")]
+    #[test_case(vec![(1, 10)], vec![(5, 20)], &[(1, 4)]; "partial overlap")]
+    #[test_case(vec![(1, 5), (10, 15)], vec![(20, 30)], &[(1, 5), (10, 15)]; "disjoint")]
+    #[test_case(vec![(3, 5), (10, 15)], vec![(0, 20)], &[]; "fully covered")]
+    #[test_case(vec![(3, 5)], vec![(3, 5)], &[]; "same")]
+    #[test_case(vec![], vec![(3, 5)], &[]; "empty")]
+    fn test_difference(left: Vec<Interval>, right: Vec<Interval>, expected: &[Interval]) {
+        let difference = IntervalSet::new(left).difference(&IntervalSet::new(right));
+        assert_eq!(difference.intervals, expected);
+        assert_eq!(difference.len(), intervals::count(expected) as usize);
+    }
+
+    #[test]
+    fn test_difference_fully_covered() {
+        let difference = uppercase_letters().difference(&letters());
+        assert!(difference.is_empty());
+        assert_eq!(difference.codepoint_at(0), None);
+    }
+
+    #[test_case(vec![(1, 10)], vec![(5, 20)], &[(1, 4), (11, 20)]; "partial overlap")]
+    #[test_case(vec![(1, 10)], vec![(3, 5)], &[(1, 2), (6, 10)]; "nested")]
+    #[test_case(vec![(1, 5)], vec![(6, 10)], &[(1, 10)]; "adjacent")]
+    #[test_case(vec![(3, 5)], vec![(3, 5)], &[]; "same")]
+    #[test_case(vec![], vec![(3, 5)], &[(3, 5)]; "empty")]
+    fn test_symmetric_difference(left: Vec<Interval>, right: Vec<Interval>, expected: &[Interval]) {
+        let left = IntervalSet::new(left);
+        let right = IntervalSet::new(right);
+        for difference in [
+            left.symmetric_difference(&right),
+            right.symmetric_difference(&left),
+        ] {
+            assert_eq!(difference.intervals, expected);
+            assert_eq!(difference.len(), intervals::count(expected) as usize);
+        }
+    }
+
+    #[test_case(vec![(0x41, 0x43)], "ABC"; "ascii")]
+    #[test_case(vec![(0xd7ff, 0xe000)], "\u{d7ff}\u{e000}"; "surrogates")]
+    #[test_case(vec![(0xd800, 0xdfff)], ""; "only surrogates")]
+    #[test_case(vec![], ""; "empty")]
+    fn test_chars(intervals: Vec<Interval>, expected: &str) {
+        let chars: String = IntervalSet::new(intervals).chars().collect();
+        assert_eq!(chars, expected);
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let interval_set = uppercase_letters();
+        let mut codepoints = Vec::with_capacity(interval_set.len());
+        for codepoint in &interval_set {
+            codepoints.push(codepoint);
+        }
+        assert_eq!(codepoints, interval_set.iter().collect::<Vec<_>>());
+    }
+
+    #[test_case(vec![]; "empty")]
+    #[test_case(vec![(5, 5)]; "single codepoint")]
+    #[test_case(vec![(1, 3), (5, 5), (10, 12)]; "multiple intervals")]
+    fn test_codepoints_rev(intervals: Vec<Interval>) {
+        let interval_set = IntervalSet::new(intervals);
+        let mut expected: Vec<_> = interval_set.iter().collect();
+        expected.reverse();
+        assert_eq!(interval_set.iter().rev().collect::<Vec<_>>(), expected);
+    }
+
+    #[test_case(vec![(1, 3), (5, 5), (10, 12)], &[1, 12, 2, 11, 3, 10, 5]; "multiple intervals")]
+    #[test_case(vec![(1, 4)], &[1, 4, 2, 3]; "single interval")]
+    fn test_codepoints_both_ends(intervals: Vec<Interval>, expected: &[u32]) {
+        let interval_set = IntervalSet::new(intervals);
+        let mut iter = interval_set.iter();
+        let mut codepoints = vec![];
+        while let Some(codepoint) = iter.next() {
+            codepoints.push(codepoint);
+            if let Some(codepoint) = iter.next_back() {
+                codepoints.push(codepoint);
+            }
+        }
+        assert_eq!(codepoints, expected);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_codepoints_len() {
+        let interval_set = uppercase_letters();
+        let mut iter = interval_set.iter();
+        assert_eq!(iter.len(), interval_set.len());
+        iter.next();
+        iter.next_back();
+        assert_eq!(Some(iter.len()), interval_set.len().checked_sub(2));
+        let len = iter.len();
+        assert_eq!(iter.count(), len);
+    }
+
+    #[cfg(feature = "rand")]
+    fn rng() -> rand::rngs::SmallRng {
+        rand::SeedableRng::seed_from_u64(42)
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        let interval_set = IntervalSet::new(vec![(1, 3), (10, 12)]);
+        let mut rng = rng();
+        let mut seen = std::collections::BTreeSet::new();
+        for _ in 0..1000 {
+            let codepoint = interval_set.sample(&mut rng).expect("Set is not empty");
+            assert!(interval_set.contains(codepoint));
+            seen.insert(codepoint);
+        }
+        assert_eq!(seen.len(), interval_set.len());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test_case(vec![]; "empty")]
+    #[test_case(vec![(0xd800, 0xdfff)]; "only surrogates")]
+    fn test_sample_char_none(intervals: Vec<Interval>) {
+        let interval_set = IntervalSet::new(intervals);
+        assert_eq!(interval_set.sample_char(&mut rng()), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_empty() {
+        assert_eq!(IntervalSet::new(vec![]).sample(&mut rng()), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_char_skips_surrogates() {
+        let interval_set = IntervalSet::new(vec![(0xd7fe, 0xe001)]);
+        let mut rng = rng();
+        let mut seen = std::collections::BTreeSet::new();
+        for _ in 0..1000 {
+            seen.insert(
+                interval_set
+                    .sample_char(&mut rng)
+                    .expect("Set has characters"),
+            );
+        }
+        assert_eq!(
+            seen.into_iter().collect::<Vec<_>>(),
+            &['\u{d7fe}', '\u{d7ff}', '\u{e000}', '\u{e001}']
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test_case(vec![(0x41, 0x43)], 0; "zero length")]
+    #[test_case(vec![(0x41, 0x43)], 20; "ascii")]
+    #[test_case(vec![(0xd7ff, 0xe000), (0x1f600, 0x1f64f)], 50; "surrogates and supplementary")]
+    fn test_sample_string(intervals: Vec<Interval>, len: usize) {
+        let interval_set = IntervalSet::new(intervals);
+        let string = interval_set.sample_string(&mut rng(), len);
+        assert_eq!(string.chars().count(), len);
+        assert!(string.chars().all(|c| interval_set.contains(c)));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test_case(vec![]; "empty")]
+    #[test_case(vec![(0xd800, 0xdfff)]; "only surrogates")]
+    fn test_sample_string_no_chars(intervals: Vec<Interval>) {
+        let interval_set = IntervalSet::new(intervals);
+        assert_eq!(interval_set.sample_string(&mut rng(), 10), "");
+    }
+
+    #[test]
+    fn test_intervals() {
+        let expected = UnicodeVersion::latest().intervals_for(UnicodeCategory::UPPERCASE_LETTER);
+        assert_eq!(uppercase_letters().intervals(), expected);
+        assert!(IntervalSet::new(vec![]).intervals().is_empty());
+    }
+
+    #[test_case(vec![(10, 20), (1, 5), (15, 30), (5, 6)], &[(1, 6), (10, 30)], 27; "overlapping")]
+    #[test_case(vec![(6, 10), (1, 5)], &[(1, 10)], 10; "adjacent")]
+    #[test_case(vec![(1, 5), (1, 5)], &[(1, 5)], 5; "duplicates")]
+    #[test_case(vec![], &[], 0; "empty")]
+    fn test_from_intervals(intervals: Vec<Interval>, expected: &[Interval], len: usize) {
+        let interval_set = IntervalSet::from_intervals(intervals);
+        assert_eq!(interval_set.intervals(), expected);
+        assert_eq!(interval_set.len(), len);
+        assert_eq!(interval_set.iter().count(), len);
+    }
+
+    #[test_case(vec![(5, 1)]; "inverted")]
+    #[test_case(vec![(0, MAX_CODEPOINT + 1)]; "too large")]
+    #[should_panic(expected = "Invalid interval")]
+    fn test_from_intervals_invalid(intervals: Vec<Interval>) {
+        let _ = IntervalSet::from_intervals(intervals);
+    }
+
+    #[test_case(vec![(10, 20), (1, 5), (15, 30)], &[(1, 5), (10, 30)]; "overlapping")]
+    #[test_case(vec![], &[]; "empty")]
+    fn test_try_from_intervals(intervals: Vec<Interval>, expected: &[Interval]) {
+        let interval_set = IntervalSet::try_from_intervals(intervals).expect("Invalid intervals");
+        assert_eq!(interval_set.intervals(), expected);
+    }
+
+    #[test_case(vec![(1, 2), (5, 1)], &Error::InvalidCodepoints(5, 1); "inverted")]
+    #[test_case(vec![(0, MAX_CODEPOINT + 1)], &Error::CodepointNotInRange(0, MAX_CODEPOINT + 1); "too large")]
+    #[test_case(vec![(u32::MAX, u32::MAX)], &Error::CodepointNotInRange(u32::MAX, u32::MAX); "overflow")]
+    fn test_try_from_intervals_invalid(intervals: Vec<Interval>, expected: &Error) {
+        let error = IntervalSet::try_from_intervals(intervals).expect_err("Should fail");
+        assert_eq!(&error, expected);
+    }
+
+    #[test]
+    fn test_collect_chars() {
+        let interval_set: IntervalSet = ('a'..='z').chain('A'..='Z').chain(['x', '0']).collect();
+        assert_eq!(interval_set.intervals(), &[(48, 48), (65, 90), (97, 122)]);
+        assert_eq!(interval_set.len(), 53);
+        let empty: IntervalSet = "".chars().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_intervals() {
+        let interval_set: IntervalSet = vec![(97, 122), (65, 90), (91, 96), (100, 110)]
+            .into_iter()
+            .collect();
+        assert_eq!(interval_set.intervals(), &[(65, 122)]);
+        assert_eq!(interval_set.len(), 58);
+        assert_eq!(interval_set.index_of('a'), Some(32));
+    }
+
+    #[test]
+    fn test_eq_hash() {
+        let old = UnicodeVersion::V9_0_0.interval_set_for_category(UnicodeCategory::Zl);
+        let new = UnicodeVersion::V15_0_0.interval_set_for_category(UnicodeCategory::Zl);
+        assert_eq!(old, new);
+        let built = IntervalSet::from_intervals(vec![(10, 20), (0, 5), (6, 9)]);
+        let merged = IntervalSet::from_intervals(vec![(0, 20)]);
+        assert_eq!(built, merged);
+        assert_ne!(built, uppercase_letters());
+        let sets: std::collections::HashSet<_> =
+            [built, merged, uppercase_letters()].into_iter().collect();
+        assert_eq!(sets.len(), 2);
+    }
+
+    #[test]
+    fn test_span() {
+        let interval_set = uppercase_letters();
+        assert_eq!(interval_set.span(), Some((65, 125217)));
+        assert_eq!(interval_set.first(), Some(65));
+        assert_eq!(interval_set.last(), Some(125217));
+        assert_eq!(interval_set.first(), interval_set.iter().next());
+        assert_eq!(interval_set.last(), interval_set.iter().next_back());
+        let empty = IntervalSet::new(vec![]);
+        assert_eq!(empty.span(), None);
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test_case(vec![(0x41, 0x5a), (0x61, 0x7a), (0x2603, 0x2603)], "41-5A,61-7A,2603"; "ranges and singles")]
+    #[test_case(vec![(0, 0)], "0"; "zero")]
+    #[test_case(vec![(0, MAX_CODEPOINT)], "0-10FFFF"; "full range")]
+    #[test_case(vec![], ""; "empty")]
+    fn test_display(intervals: Vec<Interval>, expected: &str) {
+        assert_eq!(IntervalSet::new(intervals).to_string(), expected);
+    }
+
+    #[test_case(vec![(0x41, 0x5a), (0x61, 0x7a), (0x2603, 0x2603)])]
+    #[test_case(vec![(0, MAX_CODEPOINT)])]
+    #[test_case(vec![])]
+    fn test_display_roundtrip(intervals: Vec<Interval>) {
+        let interval_set = IntervalSet::new(intervals);
+        let parsed: IntervalSet = interval_set.to_string().parse().expect("Invalid intervals");
+        assert_eq!(interval_set, parsed);
+    }
+
+    #[test]
+    fn test_display_roundtrip_letters() {
+        let interval_set = letters();
+        let parsed: IntervalSet = interval_set.to_string().parse().expect("Invalid intervals");
+        assert_eq!(interval_set, parsed);
+    }
+
+    #[test_case(" 61-7a , 41-5A ", &[(0x41, 0x5a), (0x61, 0x7a)]; "whitespace and lowercase")]
+    #[test_case("5-10,1-7,11", &[(1, 0x11)]; "unsorted and overlapping")]
+    fn test_from_str(value: &str, expected: &[Interval]) {
+        let interval_set: IntervalSet = value.parse().expect("Invalid intervals");
+        assert_eq!(interval_set.intervals(), expected);
+    }
+
+    #[test_case("41,", "'' is not a valid interval"; "trailing comma")]
+    #[test_case("41-", "'41-' is not a valid interval"; "missing right")]
+    #[test_case("-41", "'-41' is not a valid interval"; "missing left")]
+    #[test_case("1-2-3", "'1-2-3' is not a valid interval"; "too many borders")]
+    #[test_case("+41", "'+41' is not a valid interval"; "sign")]
+    #[test_case("XYZ", "'XYZ' is not a valid interval"; "not hex")]
+    #[test_case("FFFFFFFFF", "'FFFFFFFFF' is not a valid interval"; "overflow")]
+    #[test_case("5A-41", "Minimum codepoint should be less or equal than maximum codepoint. Got 90 < 65"; "inverted")]
+    #[test_case("0-110000", "Codepoints should be in [0; 1114111] range. Got: [0; 1114112]"; "too large")]
+    fn test_from_str_error(value: &str, expected: &str) {
+        let error = value.parse::<IntervalSet>().expect_err("Should fail");
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test_case(vec![(0x41, 0x5a), (0x2603, 0x2603)], r"[\x{41}-\x{5A}\x{2603}]"; "ranges and singles")]
+    #[test_case(vec![(0x5d, 0x5d)], r"[\x{5D}]"; "closing bracket")]
+    #[test_case(vec![(0x5e, 0x5e)], r"[\x{5E}]"; "caret")]
+    #[test_case(vec![(0x2d, 0x2d)], r"[\x{2D}]"; "dash")]
+    #[test_case(vec![(0x5c, 0x5c)], r"[\x{5C}]"; "backslash")]
+    #[test_case(vec![(0xd7ff, 0xe000)], r"[\x{D7FF}\x{E000}]"; "surrogates")]
+    #[test_case(vec![(0xd800, 0xdfff)], r"[^\x{0}-\x{10FFFF}]"; "only surrogates")]
+    #[test_case(vec![], r"[^\x{0}-\x{10FFFF}]"; "empty")]
+    fn test_to_regex_class(intervals: Vec<Interval>, expected: &str) {
+        assert_eq!(IntervalSet::new(intervals).to_regex_class(), expected);
+    }
+
+    #[test]
+    // INVARIANT: Codepoints are far from `u32::MAX` and the linear search mirrors `index_of`
+    #[allow(clippy::arithmetic_side_effects)]
+    fn test_index_of_matches_linear_search() {
+        fn linear_index_of(interval_set: &IntervalSet, codepoint: u32) -> Option<u32> {
+            for (offset, (left, right)) in interval_set
+                .offsets
+                .iter()
+                .zip(interval_set.intervals.iter())
+            {
+                if *left > codepoint {
+                    return None;
+                } else if codepoint <= *right {
+                    return Some(*offset + (codepoint - left));
+                }
+            }
+            None
+        }
+
+        let interval_set = crate::query()
+            .include_categories(UnicodeCategory::Lo)
+            .interval_set()
+            .expect("Invalid query input");
+        let mut codepoints: Vec<_> = (0..=MAX_CODEPOINT + 1).step_by(97).collect();
+        for (left, right) in interval_set.intervals() {
+            codepoints.extend([left.saturating_sub(1), *left, *right, right + 1]);
+        }
+        for codepoint in codepoints {
+            assert_eq!(
+                interval_set.index_of(codepoint),
+                linear_index_of(&interval_set, codepoint),
+                "{codepoint}"
+            );
+        }
+    }
+
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_interval_set_traits() {
@@ -102,15 +102,23 @@
     unused_extern_crates,
     unused_import_braces,
     variant_size_differences,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::unwrap_used,
     clippy::semicolon_if_nothing_returned,
     clippy::cargo
 )]
 #![allow(clippy::redundant_static_lifetimes)]
-use crate::constants::MAX_CODEPOINT;
+use crate::constants::{
+    ALL_VERSIONS, AMBIGUOUS_CHARACTERS, CATEGORY_COUNT, MAX_CODEPOINT, PASSWORD_SAFE_RANGE,
+    VERSION_COUNT,
+};
 use core::fmt;
-use std::str::FromStr;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    str::FromStr,
+    sync::{Arc, OnceLock},
+};
 
 mod categories;
 mod constants;
@@ -118,11 +126,16 @@ mod error;
 mod intervals;
 mod intervalset;
 mod query;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod spec;
 mod tables;
 pub use crate::{
     categories::{UnicodeCategory, UnicodeCategorySet},
     error::Error,
-    intervalset::IntervalSet,
+    intervals::find_overlaps,
+    intervalset::{Codepoints, IntervalSet},
+    spec::parse_spec,
 };
 
 #[cfg(feature = "__benchmark_internals")]
@@ -135,7 +148,7 @@ pub mod internals {
 
     /// Intervals manipulation.
     pub mod intervals {
-        pub use crate::intervals::{from_str, merge, subtract};
+        pub use crate::intervals::{from_str, intersect, merge, subtract};
     }
 
     /// Querying Unicode intervals.
@@ -277,7 +290,7 @@ impl UnicodeVersion {
             UnicodeCategory::Lu,
         ];
         // `idx` is always less than 30 and will not overflow
-        #[allow(clippy::integer_arithmetic)]
+        #[allow(clippy::arithmetic_side_effects)]
         while idx < table.len() {
             lengths[idx] = (categories[idx], table[idx].len());
             idx += 1;
@@ -289,7 +302,7 @@ impl UnicodeVersion {
             let mut swapped = false;
             let mut idx = 1;
             // Arithmetic here will not overflow as it is always less than 30 and more than 1
-            #[allow(clippy::integer_arithmetic)]
+            #[allow(clippy::arithmetic_side_effects)]
             while idx < lengths.len() {
                 if lengths[idx - 1].1 > lengths[idx].1 {
                     let left = lengths[idx - 1];
@@ -344,7 +357,7 @@ impl UnicodeVersion {
         while idx < lengths.len() {
             let (category, _) = lengths[idx];
             // `idx` & `ptr` are always less than 30 and will not overflow
-            #[allow(clippy::integer_arithmetic)]
+            #[allow(clippy::arithmetic_side_effects)]
             if category as u8 == UnicodeCategory::Cc as u8
                 || category as u8 == UnicodeCategory::Cs as u8
             {
@@ -446,6 +459,383 @@ impl UnicodeVersion {
         )?;
         Ok(IntervalSet::new(intervals))
     }
+
+    /// Build an `IntervalSet` with codepoints from the `[min, max]` range that match `f`.
+    ///
+    /// Every codepoint in the range is checked separately, therefore the complexity is
+    /// `O(max - min)`. Codepoints above `1114111` are never checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeVersion;
+    ///
+    /// let digits = UnicodeVersion::V15_0_0.set_from_predicate(0, 127, |codepoint| {
+    ///     char::from_u32(codepoint).is_some_and(|c| c.is_ascii_digit())
+    /// });
+    /// assert_eq!(digits.len(), 10);
+    /// assert!(digits.contains('7'));
+    /// ```
+    #[must_use]
+    pub fn set_from_predicate(self, min: u32, max: u32, f: impl Fn(u32) -> bool) -> IntervalSet {
+        let mut intervals: Vec<Interval> = vec![];
+        for codepoint in (min..=max.min(MAX_CODEPOINT)).filter(|codepoint| f(*codepoint)) {
+            match intervals.last_mut() {
+                // INVARIANT: `codepoint` is at most `MAX_CODEPOINT`, hence no overflow
+                #[allow(clippy::arithmetic_side_effects)]
+                Some((_, right)) if *right + 1 == codepoint => *right = codepoint,
+                _ => intervals.push((codepoint, codepoint)),
+            }
+        }
+        IntervalSet::new(intervals)
+    }
+
+    /// Build an `IntervalSet` with all codepoints except the ones in `exclude` categories.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let interval_set = UnicodeVersion::V15_0_0.interval_set_excluding_categories(UnicodeCategory::L);
+    /// assert!(!interval_set.contains('A'));
+    /// assert!(interval_set.contains('1'));
+    /// ```
+    #[must_use]
+    pub fn interval_set_excluding_categories(self, exclude: UnicodeCategorySet) -> IntervalSet {
+        IntervalSet::new(query::query(self, None, exclude, "", "", 0, MAX_CODEPOINT))
+    }
+
+    /// One representative codepoint for every non-empty Unicode category.
+    ///
+    /// The representative is the smallest codepoint of the category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let samples = UnicodeVersion::V15_0_0.category_samples();
+    /// assert!(samples.contains(&(UnicodeCategory::Lu, 'A' as u32)));
+    /// ```
+    #[must_use]
+    pub fn category_samples(self) -> Vec<(UnicodeCategory, u32)> {
+        UnicodeCategorySet::all()
+            .iter()
+            .filter_map(|category| {
+                self.intervals_for(category)
+                    .first()
+                    .map(|(left, _)| (category, *left))
+            })
+            .collect()
+    }
+
+    /// Whether `category` has identical intervals in this and `other` Unicode versions.
+    #[inline]
+    #[must_use]
+    pub fn category_unchanged(self, other: UnicodeVersion, category: UnicodeCategory) -> bool {
+        self.intervals_for(category) == other.intervals_for(category)
+    }
+
+    /// Intervals of `category` that are present in either this or `other` Unicode versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let intervals = UnicodeVersion::V9_0_0.category_union(UnicodeVersion::V15_0_0, UnicodeCategory::Lu);
+    /// assert_eq!(intervals[0], (65, 90));
+    /// ```
+    #[must_use]
+    pub fn category_union(self, other: UnicodeVersion, category: UnicodeCategory) -> Vec<Interval> {
+        let mut intervals = self.intervals_for(category).to_vec();
+        intervals.extend_from_slice(other.intervals_for(category));
+        intervals::merge(&mut intervals);
+        intervals
+    }
+
+    /// Build an `IntervalSet` for the given Unicode category.
+    ///
+    /// The set is built once per Unicode version and category and is shared afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let interval_set = UnicodeVersion::V15_0_0.interval_set_for_category(UnicodeCategory::Lu);
+    /// assert_eq!(interval_set.len(), 1831);
+    /// ```
+    #[must_use]
+    pub fn interval_set_for_category(self, category: UnicodeCategory) -> Arc<IntervalSet> {
+        // `OnceLock` is not `Copy`, therefore a constant is needed to initialize the array.
+        // Every usage of a constant creates a new instance which is exactly what is needed here
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY: OnceLock<Arc<IntervalSet>> = OnceLock::new();
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY_VERSION: [OnceLock<Arc<IntervalSet>>; CATEGORY_COUNT] = [EMPTY; CATEGORY_COUNT];
+        static CACHE: [[OnceLock<Arc<IntervalSet>>; CATEGORY_COUNT]; VERSION_COUNT] =
+            [EMPTY_VERSION; VERSION_COUNT];
+        CACHE[self as usize][category as usize]
+            .get_or_init(|| Arc::new(IntervalSet::new(self.intervals_for(category).to_vec())))
+            .clone()
+    }
+
+    /// ASCII vowels and consonants as two separate sets.
+    ///
+    /// It is a simple heuristic for generating more readable strings by alternating characters
+    /// from both sets. Vowels are `AEIOUaeiou` and consonants are all other ASCII letters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeVersion;
+    ///
+    /// let (vowels, consonants) = UnicodeVersion::V15_0_0.vowel_consonant_sets();
+    /// assert!(vowels.contains('a'));
+    /// assert!(consonants.contains('b'));
+    /// ```
+    #[must_use]
+    pub fn vowel_consonant_sets(self) -> (IntervalSet, IntervalSet) {
+        let vowels = "AEIOUaeiou";
+        let letters = query::query(
+            self,
+            Some(UnicodeCategory::Lu | UnicodeCategory::Ll),
+            UnicodeCategorySet::new(),
+            "",
+            vowels,
+            0,
+            127,
+        );
+        (
+            IntervalSet::new(intervals::from_str(vowels)),
+            IntervalSet::new(letters),
+        )
+    }
+
+    /// Unicode categories that have codepoints within the `[min, max]` range together with
+    /// the number of such codepoints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let weights = UnicodeVersion::V15_0_0.category_weights_in(0, 127);
+    /// assert!(weights.contains(&(UnicodeCategory::Lu, 26)));
+    /// ```
+    #[must_use]
+    pub fn category_weights_in(self, min: u32, max: u32) -> Vec<(UnicodeCategory, u32)> {
+        UnicodeCategorySet::all()
+            .iter()
+            .filter_map(|category| {
+                let weight = intervals::count_in(self.intervals_for(category), min, max);
+                (weight > 0).then_some((category, weight))
+            })
+            .collect()
+    }
+
+    /// The category with the fewest codepoints in total that has at least one codepoint within
+    /// the `[min, max]` range.
+    ///
+    /// If several categories have the same size, the first one in the table order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let category = UnicodeVersion::V15_0_0.smallest_category_in(0, 127);
+    /// assert_eq!(category, Some(UnicodeCategory::Pc));
+    /// ```
+    #[must_use]
+    pub fn smallest_category_in(self, min: u32, max: u32) -> Option<UnicodeCategory> {
+        UnicodeCategorySet::all()
+            .iter()
+            .filter(|category| intervals::count_in(self.intervals_for(*category), min, max) > 0)
+            .min_by_key(|category| intervals::count(self.intervals_for(*category)))
+    }
+
+    /// The category with the most codepoints.
+    ///
+    /// Unlike `normalized_categories`, categories are compared by the number of codepoints,
+    /// not intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// assert_eq!(UnicodeVersion::V15_0_0.largest_category(), UnicodeCategory::Cn);
+    /// ```
+    #[must_use]
+    pub fn largest_category(self) -> UnicodeCategory {
+        UnicodeCategorySet::all()
+            .iter()
+            .max_by_key(|category| intervals::count(self.intervals_for(*category)))
+            .unwrap_or(UnicodeCategory::Cn)
+    }
+
+    /// Unicode category of the given codepoint.
+    ///
+    /// Unassigned codepoints belong to the `Cn` category. Codepoints above `1114111` have no
+    /// category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let version = UnicodeVersion::V15_0_0;
+    /// assert_eq!(version.category_of('A' as u32), Some(UnicodeCategory::Lu));
+    /// assert_eq!(version.category_of(0x0378), Some(UnicodeCategory::Cn));
+    /// assert_eq!(version.category_of(0x110000), None);
+    /// ```
+    #[must_use]
+    pub fn category_of(self, codepoint: u32) -> Option<UnicodeCategory> {
+        UnicodeCategorySet::all().into_iter().find(|category| {
+            let intervals = self.intervals_for(*category);
+            let idx = intervals.partition_point(|(_, right)| *right < codepoint);
+            intervals
+                .get(idx)
+                .is_some_and(|(left, _)| *left <= codepoint)
+        })
+    }
+
+    /// Unicode categories of the given codepoints.
+    ///
+    /// Intervals of all categories are combined into a single sorted table once per Unicode
+    /// version, then every codepoint is found with one binary search over it instead of
+    /// searching each category table as `category_of` does. Codepoints above `1114111` have no
+    /// category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let categories = UnicodeVersion::V15_0_0.categories_of(&['a' as u32, 'A' as u32, 0x110000]);
+    /// assert_eq!(
+    ///     categories,
+    ///     [Some(UnicodeCategory::Ll), Some(UnicodeCategory::Lu), None]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn categories_of(self, codepoints: &[u32]) -> Vec<Option<UnicodeCategory>> {
+        let table = self.category_table();
+        codepoints
+            .iter()
+            .map(|codepoint| {
+                let idx = table.partition_point(|((_, right), _)| right < codepoint);
+                table
+                    .get(idx)
+                    .filter(|((left, _), _)| left <= codepoint)
+                    .map(|(_, category)| *category)
+            })
+            .collect()
+    }
+
+    /// Intervals of all categories sorted by their left bound.
+    ///
+    /// The table is built once per Unicode version and is shared afterwards.
+    fn category_table(self) -> &'static [(Interval, UnicodeCategory)] {
+        // `OnceLock` is not `Copy`, therefore a constant is needed to initialize the array.
+        // Every usage of a constant creates a new instance which is exactly what is needed here
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY: OnceLock<Vec<(Interval, UnicodeCategory)>> = OnceLock::new();
+        static CACHE: [OnceLock<Vec<(Interval, UnicodeCategory)>>; VERSION_COUNT] =
+            [EMPTY; VERSION_COUNT];
+        CACHE[self as usize].get_or_init(|| {
+            let mut table: Vec<_> = UnicodeCategorySet::all()
+                .iter()
+                .flat_map(|category| {
+                    self.intervals_for(category)
+                        .iter()
+                        .map(move |interval| (*interval, category))
+                })
+                .collect();
+            table.sort_unstable_by_key(|((left, _), _)| *left);
+            table
+        })
+    }
+
+    /// Codepoints that are assigned in this Unicode version, but are unassigned in `newer`.
+    ///
+    /// Unicode stability policies guarantee that assigned codepoints are never removed,
+    /// therefore the result is expected to be empty for any two supported versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeVersion;
+    ///
+    /// let removed = UnicodeVersion::V14_0_0.removed_codepoints(UnicodeVersion::V15_0_0);
+    /// assert!(removed.is_empty());
+    /// ```
+    #[must_use]
+    pub fn removed_codepoints(self, newer: UnicodeVersion) -> Vec<Interval> {
+        intervals::subtract(self.assigned_intervals(), &newer.assigned_intervals())
+    }
+
+    /// Build an `IntervalSet` with all control characters (`Cc`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeVersion;
+    ///
+    /// let interval_set = UnicodeVersion::V15_0_0.control_set();
+    /// assert!(interval_set.contains('\n'));
+    /// assert!(!interval_set.contains('A'));
+    /// ```
+    #[must_use]
+    pub fn control_set(self) -> IntervalSet {
+        IntervalSet::new(self.intervals_for(UnicodeCategory::Cc).to_vec())
+    }
+
+    /// Build an `IntervalSet` with all assigned codepoints except surrogates.
+    ///
+    /// It excludes unassigned (`Cn`) and surrogate (`Cs`) codepoints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::UnicodeVersion;
+    ///
+    /// let interval_set = UnicodeVersion::V15_0_0.assigned_set();
+    /// assert!(interval_set.contains('A'));
+    /// assert!(!interval_set.contains(0xd800_u32));
+    /// ```
+    #[must_use]
+    pub fn assigned_set(self) -> IntervalSet {
+        self.interval_set_excluding_categories(UnicodeCategory::Cn | UnicodeCategory::Cs)
+    }
+
+    fn assigned_intervals(self) -> Vec<Interval> {
+        intervals::subtract(
+            vec![(0, MAX_CODEPOINT)],
+            self.intervals_for(UnicodeCategory::Cn),
+        )
+    }
+
+    /// The number of codepoints in `category` for every supported Unicode version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    ///
+    /// let growth = UnicodeVersion::category_growth(UnicodeCategory::Lo);
+    /// assert_eq!(growth[0], (UnicodeVersion::V9_0_0, 112_721));
+    /// ```
+    #[must_use]
+    pub fn category_growth(category: UnicodeCategory) -> Vec<(UnicodeVersion, u32)> {
+        ALL_VERSIONS
+            .iter()
+            .map(|version| (*version, intervals::count(version.intervals_for(category))))
+            .collect()
+    }
 }
 
 /// A Query builder for specifying the input parameters to the `intervals()` method in `UnicodeVersion`.
@@ -473,8 +863,10 @@ pub struct IntervalQuery<'a> {
     exclude_categories: Option<UnicodeCategorySet>,
     include_characters: Option<&'a str>,
     exclude_characters: Option<&'a str>,
+    exclude_codepoints: Option<&'a [u32]>,
     min_codepoint: u32,
     max_codepoint: u32,
+    password_safe: bool,
 }
 
 impl<'a> IntervalQuery<'a> {
@@ -485,11 +877,16 @@ impl<'a> IntervalQuery<'a> {
             exclude_categories: None,
             include_characters: None,
             exclude_characters: None,
+            exclude_codepoints: None,
             min_codepoint: 0,
             max_codepoint: MAX_CODEPOINT,
+            password_safe: false,
         }
     }
     /// Set `include_categories`.
+    ///
+    /// An empty set includes no categories at all, so combined with `include_characters` the
+    /// result consists of exactly those characters.
     #[must_use]
     pub fn include_categories(
         mut self,
@@ -508,17 +905,148 @@ impl<'a> IntervalQuery<'a> {
         self
     }
     /// Set `include_characters`.
+    ///
+    /// These characters are always added to the result, even if no categories match.
     #[must_use]
     pub fn include_characters(mut self, include_characters: &'a str) -> IntervalQuery<'a> {
         self.include_characters = Some(include_characters);
         self
     }
+    /// Add punctuation (`P`) and symbols (`S`) to `include_categories`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .punctuation_and_symbols()
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(interval_set.contains('!'));
+    /// assert!(interval_set.contains('$'));
+    /// assert!(!interval_set.contains('A'));
+    /// ```
+    #[must_use]
+    pub fn punctuation_and_symbols(mut self) -> IntervalQuery<'a> {
+        self.include_categories = Some(
+            self.include_categories.unwrap_or_default() | UnicodeCategory::P | UnicodeCategory::S,
+        );
+        self
+    }
+    /// Add a major category group to `include_categories` by its letter.
+    ///
+    /// Supported groups are `L`, `M`, `N`, `P`, `S`, `Z` and `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .include_group('N')
+    ///     .expect("Invalid group")
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(interval_set.contains('7'));
+    /// assert!(!interval_set.contains('A'));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `group` is not one of the supported group letters.
+    pub fn include_group(mut self, group: char) -> Result<IntervalQuery<'a>, Error> {
+        let categories = match group {
+            'L' => UnicodeCategory::L,
+            'M' => UnicodeCategory::M,
+            'N' => UnicodeCategory::N,
+            'P' => UnicodeCategory::P,
+            'S' => UnicodeCategory::S,
+            'Z' => UnicodeCategory::Z,
+            'C' => UnicodeCategory::C,
+            _ => return Err(Error::InvalidCategory(group.to_string().into_boxed_str())),
+        };
+        self.include_categories = Some(self.include_categories.unwrap_or_default() | categories);
+        Ok(self)
+    }
+    /// Add surrogates (`Cs`), unassigned codepoints (`Cn`) and private use characters (`Co`)
+    /// to `exclude_categories`.
+    ///
+    /// This way the result consists only of assigned, non-private codepoints that are valid
+    /// `char` values. Characters from `include_characters` are still added to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .safe()
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(interval_set.contains('A'));
+    /// assert!(!interval_set.contains(0xd800_u32));
+    /// assert!(!interval_set.contains(0xe000_u32));
+    /// ```
+    #[must_use]
+    pub fn safe(mut self) -> IntervalQuery<'a> {
+        self.exclude_categories = Some(
+            self.exclude_categories.unwrap_or_default()
+                | UnicodeCategory::Cs
+                | UnicodeCategory::Cn
+                | UnicodeCategory::Co,
+        );
+        self
+    }
+    /// Restrict the query to printable ASCII characters that are safe to use in passwords.
+    ///
+    /// It includes ASCII letters, digits, punctuation and symbols from `!` (0x21) to `~` (0x7E)
+    /// and excludes visually ambiguous characters: `0`, `O`, `1`, `l`, `I` and `|`.
+    ///
+    /// The restriction is applied on top of other query parameters regardless of the order
+    /// of calls: `min_codepoint` and `max_codepoint` can only narrow the range further and
+    /// `exclude_characters` is combined with the ambiguous characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let interval_set = unicode_intervals::query()
+    ///     .password_safe()
+    ///     .exclude_characters("#")
+    ///     .interval_set()
+    ///     .expect("Invalid query input");
+    /// assert!(interval_set.contains('a'));
+    /// assert!(!interval_set.contains('#'));
+    /// assert!(!interval_set.contains('0'));
+    /// assert!(!interval_set.contains('O'));
+    /// ```
+    #[must_use]
+    pub fn password_safe(mut self) -> IntervalQuery<'a> {
+        self.password_safe = true;
+        self
+    }
     /// Set `exclude_characters`.
+    ///
+    /// Exclusion takes precedence: characters present in both `include_characters` and
+    /// `exclude_characters` are not in the result. See `IntervalQuery::char_conflict`.
     #[must_use]
     pub fn exclude_characters(mut self, exclude_characters: &'a str) -> IntervalQuery<'a> {
         self.exclude_characters = Some(exclude_characters);
         self
     }
+    /// Set `exclude_codepoints`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let intervals = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(70)
+    ///     .exclude_codepoints(&[65, 67, 69])
+    ///     .intervals()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(intervals, &[(66, 66), (68, 68), (70, 70)]);
+    /// ```
+    #[must_use]
+    pub fn exclude_codepoints(mut self, exclude_codepoints: &'a [u32]) -> IntervalQuery<'a> {
+        self.exclude_codepoints = Some(exclude_codepoints);
+        self
+    }
     /// Set `min_codepoint`.
     #[must_use]
     pub fn min_codepoint(mut self, min_codepoint: u32) -> IntervalQuery<'a> {
@@ -531,20 +1059,244 @@ impl<'a> IntervalQuery<'a> {
         self.max_codepoint = max_codepoint;
         self
     }
+    /// Intervals parsed from `include_characters` and `exclude_characters`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let query = unicode_intervals::query()
+    ///     .include_characters("abc")
+    ///     .exclude_characters("z");
+    /// assert_eq!(query.net_characters(), (vec![(97, 99)], vec![(122, 122)]));
+    /// ```
+    #[must_use]
+    pub fn net_characters(&self) -> (Vec<Interval>, Vec<Interval>) {
+        (
+            intervals::from_str(self.include_characters.unwrap_or("")),
+            intervals::from_str(self.exclude_characters.unwrap_or("")),
+        )
+    }
+    /// Characters present in both `include_characters` and `exclude_characters`.
+    ///
+    /// Such characters are excluded from the result. The returned characters are sorted and
+    /// contain no duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let query = unicode_intervals::query()
+    ///     .include_characters("abc")
+    ///     .exclude_characters("b");
+    /// assert_eq!(query.char_conflict(), vec!['b']);
+    /// ```
+    #[must_use]
+    pub fn char_conflict(&self) -> Vec<char> {
+        let (include, exclude) = self.net_characters();
+        intervals::intersect_iter(&include, &exclude)
+            .flat_map(|(left, right)| left..=right)
+            .filter_map(char::from_u32)
+            .collect()
+    }
+    /// Check the query input and report all found problems at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let errors = unicode_intervals::query()
+    ///     .min_codepoint(2000000)
+    ///     .max_codepoint(5)
+    ///     .validate()
+    ///     .expect_err("Invalid query input");
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - `min_codepoint > max_codepoint`
+    ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
+    pub fn validate(&self) -> Result<(), Vec<Error>> {
+        let mut errors = vec![];
+        if self.min_codepoint > MAX_CODEPOINT || self.max_codepoint > MAX_CODEPOINT {
+            errors.push(Error::CodepointNotInRange(
+                self.min_codepoint,
+                self.max_codepoint,
+            ));
+        }
+        if self.min_codepoint > self.max_codepoint {
+            errors.push(Error::InvalidCodepoints(
+                self.min_codepoint,
+                self.max_codepoint,
+            ));
+        }
+        for codepoint in self.exclude_codepoints.unwrap_or(&[]) {
+            if *codepoint > MAX_CODEPOINT {
+                errors.push(Error::CodepointNotInRange(*codepoint, *codepoint));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
     /// Find intervals matching the query.
     ///
     /// # Errors
     ///
     ///   - `min_codepoint > max_codepoint`
     ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
     pub fn intervals(&self) -> Result<Vec<Interval>, Error> {
-        self.version.intervals(
+        let exclude_codepoints = self.exclude_codepoints.unwrap_or(&[]);
+        if let Some(codepoint) = exclude_codepoints
+            .iter()
+            .find(|codepoint| **codepoint > MAX_CODEPOINT)
+        {
+            return Err(Error::CodepointNotInRange(*codepoint, *codepoint));
+        }
+        let intervals = self.version.intervals(
             self.include_categories,
             self.exclude_categories,
             self.include_characters,
             self.exclude_characters,
             self.min_codepoint,
             self.max_codepoint,
+        )?;
+        let mut intervals =
+            intervals::subtract(intervals, &intervals::from_codepoints(exclude_codepoints));
+        if self.password_safe {
+            intervals = intervals::subtract(
+                intervals::intersect(&intervals, &[PASSWORD_SAFE_RANGE]),
+                &intervals::from_str(AMBIGUOUS_CHARACTERS),
+            );
+        }
+        Ok(intervals)
+    }
+    /// The number of intervals matching the query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let count = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(90)
+    ///     .exclude_characters("ACE")
+    ///     .result_interval_count()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(count, 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - `min_codepoint > max_codepoint`
+    ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
+    pub fn result_interval_count(&self) -> Result<usize, Error> {
+        Ok(self.intervals()?.len())
+    }
+    /// The number of codepoints matching the query in every supported Unicode version.
+    ///
+    /// The query's own Unicode version is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+    /// let counts = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::Lo)
+    ///     .result_per_version()
+    ///     .expect("Invalid query input");
+    /// assert_eq!(counts[0], (UnicodeVersion::V9_0_0, 112_721));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - `min_codepoint > max_codepoint`
+    ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
+    pub fn result_per_version(&self) -> Result<Vec<(UnicodeVersion, usize)>, Error> {
+        ALL_VERSIONS
+            .iter()
+            .map(|version| {
+                let query = IntervalQuery {
+                    version: *version,
+                    ..self.clone()
+                };
+                let count = intervals::count(&query.intervals()?);
+                Ok((*version, count as usize))
+            })
+            .collect()
+    }
+    /// Whether the number of codepoints matching the query is greater than `max_codepoints`.
+    ///
+    /// The answer is first estimated from per-category codepoint counts and the sizes of
+    /// included and excluded characters, without building the resulting intervals. The query
+    /// is fully evaluated only if the estimate is inconclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use unicode_intervals::UnicodeCategory;
+    /// let query = unicode_intervals::query()
+    ///     .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+    ///     .max_codepoint(127);
+    /// assert!(!query.would_exceed(26).expect("Invalid query input"));
+    /// assert!(query.would_exceed(25).expect("Invalid query input"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///   - `min_codepoint > max_codepoint`
+    ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
+    pub fn would_exceed(&self, max_codepoints: u32) -> Result<bool, Error> {
+        if self.validate().is_ok() {
+            let (lower, upper) = self.count_bounds();
+            if lower > max_codepoints {
+                return Ok(true);
+            }
+            if upper <= max_codepoints {
+                return Ok(false);
+            }
+        }
+        Ok(intervals::count(&self.intervals()?) > max_codepoints)
+    }
+    /// Lower and upper bounds for the number of codepoints matching a valid query.
+    fn count_bounds(&self) -> (u32, u32) {
+        let (mut min_codepoint, mut max_codepoint) = (self.min_codepoint, self.max_codepoint);
+        let mut excluded =
+            intervals::count(&intervals::from_str(self.exclude_characters.unwrap_or("")))
+                .saturating_add(intervals::count(&intervals::from_codepoints(
+                    self.exclude_codepoints.unwrap_or(&[]),
+                )));
+        if self.password_safe {
+            min_codepoint = min_codepoint.max(PASSWORD_SAFE_RANGE.0);
+            max_codepoint = max_codepoint.min(PASSWORD_SAFE_RANGE.1);
+            excluded = excluded
+                .saturating_add(intervals::count(&intervals::from_str(AMBIGUOUS_CHARACTERS)));
+        }
+        // Categories do not overlap, therefore their counts could be summed up
+        let mut in_categories: u32 = 0;
+        if min_codepoint <= max_codepoint {
+            let categories = categories::merge(
+                self.include_categories,
+                self.exclude_categories.unwrap_or_default(),
+            );
+            for category in categories {
+                in_categories = in_categories.saturating_add(intervals::count_in(
+                    self.version.intervals_for(category),
+                    min_codepoint,
+                    max_codepoint,
+                ));
+            }
+        }
+        let included =
+            intervals::count(&intervals::from_str(self.include_characters.unwrap_or("")));
+        (
+            in_categories.saturating_sub(excluded),
+            in_categories.saturating_add(included),
         )
     }
     /// Build an `IndexSet` for the intervals matching the query.
@@ -553,15 +1305,35 @@ impl<'a> IntervalQuery<'a> {
     ///
     ///   - `min_codepoint > max_codepoint`
     ///   - `min_codepoint > 1114111` or `max_codepoint > 1114111`
+    ///   - Any of `exclude_codepoints` is `> 1114111`
     pub fn interval_set(&self) -> Result<IntervalSet, Error> {
-        self.version.interval_set(
-            self.include_categories,
-            self.exclude_categories,
-            self.include_characters,
-            self.exclude_characters,
-            self.min_codepoint,
-            self.max_codepoint,
-        )
+        Ok(IntervalSet::new(self.intervals()?))
+    }
+    /// A hash of the Unicode version and all query parameters.
+    ///
+    /// Equal queries produce equal keys, so it could be used as a key for caching query results.
+    /// The key is stable within the same build of the crate, but it should not be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let first = unicode_intervals::query().max_codepoint(127);
+    /// let second = unicode_intervals::query().max_codepoint(127);
+    /// assert_eq!(first.cache_key(), second.cache_key());
+    /// ```
+    #[must_use]
+    pub fn cache_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.version.hash(&mut hasher);
+        self.include_categories.hash(&mut hasher);
+        self.exclude_categories.hash(&mut hasher);
+        self.include_characters.hash(&mut hasher);
+        self.exclude_characters.hash(&mut hasher);
+        self.exclude_codepoints.hash(&mut hasher);
+        self.min_codepoint.hash(&mut hasher);
+        self.max_codepoint.hash(&mut hasher);
+        self.password_safe.hash(&mut hasher);
+        hasher.finish()
     }
 }
 
@@ -572,6 +1344,61 @@ pub fn query<'a>() -> IntervalQuery<'a> {
     UnicodeVersion::latest().query()
 }
 
+/// Intervals added to `category` between every two consecutive supported Unicode versions.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_intervals::{UnicodeCategory, UnicodeVersion};
+///
+/// let timeline = unicode_intervals::category_timeline(UnicodeCategory::Lo);
+/// let (older, newer, added) = &timeline[0];
+/// assert_eq!(*older, UnicodeVersion::V9_0_0);
+/// assert_eq!(*newer, UnicodeVersion::V10_0_0);
+/// assert!(!added.is_empty());
+/// ```
+#[must_use]
+pub fn category_timeline(
+    category: UnicodeCategory,
+) -> Vec<(UnicodeVersion, UnicodeVersion, Vec<Interval>)> {
+    ALL_VERSIONS
+        .windows(2)
+        .map(|pair| {
+            let (older, newer) = (pair[0], pair[1]);
+            let added = intervals::subtract(
+                newer.intervals_for(category).to_vec(),
+                older.intervals_for(category),
+            );
+            (older, newer, added)
+        })
+        .collect()
+}
+
+/// Intervals of `category` that are present in every supported Unicode version.
+///
+/// Characters from these intervals have the same category in all supported versions.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_intervals::UnicodeCategory;
+///
+/// let intervals = unicode_intervals::stable_intervals(UnicodeCategory::Lu);
+/// assert_eq!(intervals[0], (65, 90));
+/// ```
+#[must_use]
+pub fn stable_intervals(category: UnicodeCategory) -> Vec<Interval> {
+    let mut versions = ALL_VERSIONS.iter();
+    let mut intervals = versions
+        .next()
+        .map(|version| version.intervals_for(category).to_vec())
+        .unwrap_or_default();
+    for version in versions {
+        intervals = intervals::intersect(&intervals, version.intervals_for(category));
+    }
+    intervals
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -632,6 +1459,17 @@ mod tests {
         assert_eq!(intervals, &[(97, 99)]);
     }
 
+    #[test]
+    fn test_query_empty_categories_with_characters() {
+        let intervals = UnicodeVersion::V15_0_0
+            .query()
+            .include_categories(UnicodeCategorySet::new())
+            .include_characters("abc")
+            .intervals()
+            .expect("Invalid query");
+        assert_eq!(intervals, &[(97, 99)]);
+    }
+
     #[test]
     fn test_query_exclude_only_characters() {
         let intervals = UnicodeVersion::V15_0_0
@@ -644,6 +1482,293 @@ mod tests {
         assert_eq!(intervals, &[(68, 90)]);
     }
 
+    #[test]
+    fn test_query_exclude_codepoints() {
+        let interval_set = UnicodeVersion::V15_0_0
+            .query()
+            .include_categories(UnicodeCategory::UPPERCASE_LETTER)
+            .exclude_codepoints(&[65, 67, 69])
+            .interval_set()
+            .expect("Invalid query");
+        assert!(!interval_set.contains('A'));
+        assert!(interval_set.contains('B'));
+        assert!(!interval_set.contains('C'));
+        assert!(interval_set.contains('D'));
+        assert!(!interval_set.contains('E'));
+        assert_eq!(interval_set.len(), 1828);
+    }
+
+    #[test]
+    fn test_query_exclude_codepoints_invalid() {
+        let error = UnicodeVersion::V15_0_0
+            .query()
+            .exclude_codepoints(&[65, 1114112])
+            .intervals()
+            .expect_err("Should error");
+        assert_eq!(
+            error.to_string(),
+            "Codepoints should be in [0; 1114111] range. Got: [1114112; 1114112]"
+        );
+    }
+
+    #[test]
+    fn test_query_punctuation_and_symbols() {
+        let interval_set = UnicodeVersion::V15_0_0
+            .query()
+            .include_categories(UnicodeCategory::Nd)
+            .punctuation_and_symbols()
+            .max_codepoint(127)
+            .interval_set()
+            .expect("Invalid query");
+        for codepoint in ['!', '$', '+', '0', '_'] {
+            assert!(interval_set.contains(codepoint));
+        }
+        for codepoint in ['A', 'z', ' '] {
+            assert!(!interval_set.contains(codepoint));
+        }
+    }
+
+    #[test]
+    fn test_query_safe() {
+        let version = UnicodeVersion::V15_0_0;
+        let intervals = version
+            .query()
+            .exclude_categories(UnicodeCategory::Lu)
+            .safe()
+            .intervals()
+            .expect("Invalid query");
+        for category in [
+            UnicodeCategory::Cs,
+            UnicodeCategory::Cn,
+            UnicodeCategory::Co,
+            UnicodeCategory::Lu,
+        ] {
+            assert!(intervals::intersect(&intervals, version.intervals_for(category)).is_empty());
+        }
+        assert!(intervals.iter().all(
+            |(left, right)| char::from_u32(*left).is_some() && char::from_u32(*right).is_some()
+        ));
+    }
+
+    #[test_case("abc", "b", &['b'])]
+    #[test_case("abcabc", "cba", &['a', 'b', 'c'])]
+    #[test_case("abc", "xyz", &[])]
+    #[test_case("", "b", &[])]
+    fn test_query_char_conflict(include: &str, exclude: &str, expected: &[char]) {
+        let query = query()
+            .include_characters(include)
+            .exclude_characters(exclude);
+        assert_eq!(query.char_conflict(), expected);
+        let intervals = query.intervals().expect("Invalid query");
+        for c in expected {
+            assert!(!intervals
+                .iter()
+                .any(|(left, right)| *left <= u32::from(*c) && u32::from(*c) <= *right));
+        }
+    }
+
+    #[test]
+    fn test_query_cache_key() {
+        let first = query()
+            .include_categories(UnicodeCategory::L)
+            .include_characters("abc")
+            .max_codepoint(127);
+        let second = query()
+            .include_categories(UnicodeCategory::L)
+            .include_characters("abc")
+            .max_codepoint(127);
+        assert_eq!(first.cache_key(), second.cache_key());
+        assert_ne!(
+            first.cache_key(),
+            first.clone().max_codepoint(128).cache_key()
+        );
+        assert_ne!(
+            first.cache_key(),
+            first.clone().exclude_characters("abc").cache_key()
+        );
+        assert_ne!(
+            query().cache_key(),
+            UnicodeVersion::V9_0_0.query().cache_key()
+        );
+    }
+
+    #[test_case(&query(), 1000, true)]
+    #[test_case(&query(), 0x110000, false)]
+    #[test_case(&query().include_categories(UnicodeCategory::Lu).max_codepoint(127), 26, false)]
+    #[test_case(&query().include_categories(UnicodeCategory::Lu).max_codepoint(127), 25, true)]
+    #[test_case(&query().include_categories(UnicodeCategorySet::new()), 0, false)]
+    fn test_query_would_exceed(query: &IntervalQuery, max_codepoints: u32, expected: bool) {
+        assert_eq!(
+            query.would_exceed(max_codepoints).expect("Invalid query"),
+            expected
+        );
+    }
+
+    #[test_case(&query(); "all")]
+    #[test_case(&query().include_categories(UnicodeCategory::L).max_codepoint(1000); "range")]
+    #[test_case(&query().include_categories(UnicodeCategory::Lu).include_characters("abc0A"); "include characters")]
+    #[test_case(&query().include_categories(UnicodeCategory::Lu).max_codepoint(127).exclude_characters("ABa"); "exclude characters")]
+    #[test_case(&query().include_categories(UnicodeCategory::Lu).exclude_codepoints(&[65, 65, 97]); "exclude codepoints")]
+    #[test_case(&query().exclude_categories(UnicodeCategory::L).min_codepoint(50).max_codepoint(300); "exclude categories")]
+    #[test_case(&query().include_categories(UnicodeCategorySet::new()).include_characters("xyz"); "only characters")]
+    #[test_case(&query().password_safe().exclude_characters("x"); "password safe")]
+    #[test_case(&query().password_safe().min_codepoint(0x100); "password safe empty range")]
+    fn test_query_would_exceed_matches_count(query: &IntervalQuery) {
+        let count = intervals::count(&query.intervals().expect("Invalid query"));
+        let (lower, upper) = query.count_bounds();
+        assert!(
+            lower <= count && count <= upper,
+            "{lower} <= {count} <= {upper}"
+        );
+        for max_codepoints in [0, count.saturating_sub(1), count, count.saturating_add(1)] {
+            assert_eq!(
+                query.would_exceed(max_codepoints).expect("Invalid query"),
+                count > max_codepoints
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_would_exceed_invalid() {
+        assert!(query()
+            .min_codepoint(5)
+            .max_codepoint(1)
+            .would_exceed(10)
+            .is_err());
+        assert!(query()
+            .exclude_codepoints(&[u32::MAX])
+            .would_exceed(10)
+            .is_err());
+    }
+
+    #[test_case("abc", "", &[(97, 99)], &[])]
+    #[test_case("cab", "zx", &[(97, 99)], &[(120, 120), (122, 122)])]
+    #[test_case("", "", &[], &[])]
+    fn test_query_net_characters(
+        include: &str,
+        exclude: &str,
+        expected_include: &[Interval],
+        expected_exclude: &[Interval],
+    ) {
+        let (include, exclude) = query()
+            .include_characters(include)
+            .exclude_characters(exclude)
+            .net_characters();
+        assert_eq!(include, expected_include);
+        assert_eq!(exclude, expected_exclude);
+    }
+
+    #[test]
+    fn test_query_include_group() {
+        let by_group = query()
+            .include_group('L')
+            .expect("Invalid group")
+            .intervals()
+            .expect("Invalid query");
+        let by_categories = query()
+            .include_categories(UnicodeCategory::L)
+            .intervals()
+            .expect("Invalid query");
+        assert_eq!(by_group, by_categories);
+        let query = query()
+            .include_group('L')
+            .and_then(|query| query.include_group('N'))
+            .expect("Invalid group");
+        assert_eq!(
+            query,
+            crate::query().include_categories(UnicodeCategory::L | UnicodeCategory::N)
+        );
+    }
+
+    #[test]
+    fn test_query_include_group_invalid() {
+        assert_eq!(
+            query()
+                .include_group('X')
+                .expect_err("Should fail")
+                .to_string(),
+            "'X' is not a valid Unicode category"
+        );
+    }
+
+    #[test]
+    fn test_query_result_per_version() {
+        let ascii = query()
+            .include_categories(UnicodeCategory::L)
+            .max_codepoint(127)
+            .result_per_version()
+            .expect("Invalid query");
+        assert_eq!(ascii.len(), ALL_VERSIONS.len());
+        assert!(ascii.iter().all(|(_, count)| *count == 52));
+        let letters = query()
+            .include_categories(UnicodeCategory::L)
+            .result_per_version()
+            .expect("Invalid query");
+        let (_, first) = letters[0];
+        let (_, last) = letters.last().expect("Versions are not empty");
+        assert!(first < *last);
+        assert!(query()
+            .min_codepoint(5)
+            .max_codepoint(1)
+            .result_per_version()
+            .is_err());
+    }
+
+    #[test]
+    fn test_query_password_safe() {
+        let interval_set = UnicodeVersion::V15_0_0
+            .query()
+            .password_safe()
+            .interval_set()
+            .expect("Invalid query");
+        assert_eq!(interval_set.len(), 88);
+        for codepoint in ['a', 'Z', '2', '!', '~'] {
+            assert!(interval_set.contains(codepoint));
+        }
+        for codepoint in ['0', 'O', '1', 'l', 'I', '|', ' ', '\n', '\u{7f}'] {
+            assert!(!interval_set.contains(codepoint));
+        }
+    }
+
+    #[test_case(&query().password_safe().exclude_characters("x"); "exclusions after")]
+    #[test_case(&query().exclude_characters("x").password_safe(); "exclusions before")]
+    fn test_query_password_safe_keeps_exclusions(query: &IntervalQuery<'_>) {
+        let interval_set = query.interval_set().expect("Invalid query");
+        assert_eq!(interval_set.len(), 87);
+        for codepoint in ['x', '0', 'O', '1', 'l', 'I', '|'] {
+            assert!(!interval_set.contains(codepoint));
+        }
+    }
+
+    #[test_case(&query().password_safe().min_codepoint(0x61).max_codepoint(0x7a); "range after")]
+    #[test_case(&query().min_codepoint(0x61).max_codepoint(0x7a).password_safe(); "range before")]
+    #[test_case(&query().min_codepoint(0x61).password_safe().max_codepoint(0x7a); "mixed")]
+    fn test_query_password_safe_keeps_range(query: &IntervalQuery<'_>) {
+        let intervals = query.intervals().expect("Invalid query");
+        // Lowercase ASCII letters without `l`
+        assert_eq!(intervals, &[(0x61, 0x6b), (0x6d, 0x7a)]);
+    }
+
+    #[test]
+    fn test_query_password_safe_cache_key() {
+        assert_ne!(query().cache_key(), query().password_safe().cache_key());
+    }
+
+    #[test]
+    fn test_query_result_interval_count() {
+        let query = UnicodeVersion::V15_0_0
+            .query()
+            .include_categories(UnicodeCategory::UPPERCASE_LETTER);
+        let count = query.result_interval_count().expect("Invalid query");
+        assert_eq!(count, 646);
+        let count_with_exclusions = query
+            .exclude_characters("ACE")
+            .result_interval_count()
+            .expect("Invalid query");
+        // `A` is at the start of an interval, but `C` & `E` split it
+        assert_eq!(count_with_exclusions, count + 2);
+    }
+
     #[test]
     fn test_query_exclude_categories() {
         let intervals = UnicodeVersion::V15_0_0
@@ -706,6 +1831,26 @@ mod tests {
         assert_eq!(error.to_string(), expected);
     }
 
+    #[test]
+    fn test_query_validate() {
+        assert_eq!(query().validate(), Ok(()));
+        let errors = query()
+            .min_codepoint(2000000)
+            .max_codepoint(5)
+            .exclude_codepoints(&[65, 1114112, 1114113])
+            .validate()
+            .expect_err("Should error");
+        assert_eq!(
+            errors,
+            vec![
+                Error::CodepointNotInRange(2000000, 5),
+                Error::InvalidCodepoints(2000000, 5),
+                Error::CodepointNotInRange(1114112, 1114112),
+                Error::CodepointNotInRange(1114113, 1114113),
+            ]
+        );
+    }
+
     #[test]
     fn test_intervals_for() {
         assert_eq!(
@@ -777,6 +1922,323 @@ mod tests {
         assert_eq!(x, vec![(0, MAX_CODEPOINT)]);
     }
 
+    #[test_case(UnicodeVersion::V9_0_0)]
+    #[test_case(UnicodeVersion::V15_0_0)]
+    fn test_category_samples(version: UnicodeVersion) {
+        let samples = version.category_samples();
+        assert_eq!(samples.len(), 30);
+        for (category, codepoint) in samples {
+            for other in UnicodeCategorySet::all() {
+                let is_member = version
+                    .intervals_for(other)
+                    .iter()
+                    .any(|(left, right)| (*left..=*right).contains(&codepoint));
+                assert_eq!(is_member, other == category);
+            }
+        }
+    }
+
+    #[test_case(
+        UnicodeVersion::V9_0_0,
+        UnicodeVersion::V15_0_0,
+        UnicodeCategory::Zl,
+        true
+    )]
+    #[test_case(
+        UnicodeVersion::V9_0_0,
+        UnicodeVersion::V15_0_0,
+        UnicodeCategory::Lu,
+        false
+    )]
+    #[test_case(
+        UnicodeVersion::V12_0_0,
+        UnicodeVersion::V12_1_0,
+        UnicodeCategory::So,
+        false
+    )]
+    #[test_case(
+        UnicodeVersion::V15_0_0,
+        UnicodeVersion::V15_0_0,
+        UnicodeCategory::Lo,
+        true
+    )]
+    fn test_category_unchanged(
+        version: UnicodeVersion,
+        other: UnicodeVersion,
+        category: UnicodeCategory,
+        expected: bool,
+    ) {
+        assert_eq!(version.category_unchanged(other, category), expected);
+        assert_eq!(other.category_unchanged(version, category), expected);
+    }
+
+    #[test_case(UnicodeCategory::Lo)]
+    #[test_case(UnicodeCategory::So)]
+    #[test_case(UnicodeCategory::Mn)]
+    fn test_category_growth(category: UnicodeCategory) {
+        let growth = UnicodeVersion::category_growth(category);
+        assert_eq!(growth.len(), ALL_VERSIONS.len());
+        for window in growth.windows(2) {
+            assert!(window[0].1 <= window[1].1);
+        }
+        assert!(growth[0].1 < growth.last().expect("Empty growth").1);
+    }
+
+    #[test]
+    fn test_smallest_category_in() {
+        let version = UnicodeVersion::V15_0_0;
+        let category = version
+            .smallest_category_in(0, 127)
+            .expect("ASCII has categories");
+        let size = intervals::count(version.intervals_for(category));
+        for (other, _) in version.category_weights_in(0, 127) {
+            assert!(size <= intervals::count(version.intervals_for(other)));
+        }
+        // Only `Cn` is in this range
+        assert_eq!(
+            version.smallest_category_in(0x10fffe, 0x10fffe),
+            Some(UnicodeCategory::Cn)
+        );
+        assert_eq!(version.smallest_category_in(127, 0), None);
+    }
+
+    #[test_case(UnicodeVersion::V9_0_0)]
+    #[test_case(UnicodeVersion::V15_0_0)]
+    fn test_largest_category(version: UnicodeVersion) {
+        let largest = version.largest_category();
+        assert_eq!(largest, UnicodeCategory::Cn);
+        let size = intervals::count(version.intervals_for(largest));
+        for category in UnicodeCategorySet::all() {
+            assert!(intervals::count(version.intervals_for(category)) <= size);
+        }
+    }
+
+    #[test_case('A' as u32, Some(UnicodeCategory::Lu))]
+    #[test_case('a' as u32, Some(UnicodeCategory::Ll))]
+    #[test_case('1' as u32, Some(UnicodeCategory::Nd))]
+    #[test_case(0, Some(UnicodeCategory::Cc))]
+    #[test_case(0xd800, Some(UnicodeCategory::Cs))]
+    #[test_case(0x0378, Some(UnicodeCategory::Cn))]
+    #[test_case(MAX_CODEPOINT, Some(UnicodeCategory::Cn))]
+    #[test_case(0x110000, None)]
+    fn test_category_of(codepoint: u32, expected: Option<UnicodeCategory>) {
+        assert_eq!(UnicodeVersion::V15_0_0.category_of(codepoint), expected);
+    }
+
+    #[test_case(UnicodeVersion::V9_0_0)]
+    #[test_case(UnicodeVersion::V15_0_0)]
+    fn test_category_of_matches_tables(version: UnicodeVersion) {
+        for category in UnicodeCategorySet::all() {
+            for (left, right) in version.intervals_for(category) {
+                assert_eq!(version.category_of(*left), Some(category));
+                assert_eq!(version.category_of(*right), Some(category));
+            }
+        }
+    }
+
+    #[test]
+    fn test_categories_of() {
+        let version = UnicodeVersion::V15_0_0;
+        let codepoints = [
+            0x1f600, 'a' as u32, 0, 0x10ffff, 0x110000, 'A' as u32, 'a' as u32,
+        ];
+        let expected: Vec<_> = codepoints
+            .iter()
+            .map(|codepoint| version.category_of(*codepoint))
+            .collect();
+        assert_eq!(version.categories_of(&codepoints), expected);
+        assert_eq!(expected[1], Some(UnicodeCategory::Ll));
+        assert_eq!(expected[4], None);
+    }
+
+    #[test_case(UnicodeVersion::V9_0_0)]
+    #[test_case(UnicodeVersion::V15_0_0)]
+    fn test_categories_of_matches_category_of(version: UnicodeVersion) {
+        let codepoints: Vec<_> = (0..=0x0011_0000).step_by(101).collect();
+        let expected: Vec<_> = codepoints
+            .iter()
+            .map(|codepoint| version.category_of(*codepoint))
+            .collect();
+        assert_eq!(version.categories_of(&codepoints), expected);
+    }
+
+    #[test]
+    fn test_category_union() {
+        let union =
+            UnicodeVersion::V9_0_0.category_union(UnicodeVersion::V15_0_0, UnicodeCategory::Lu);
+        for version in [UnicodeVersion::V9_0_0, UnicodeVersion::V15_0_0] {
+            for (left, right) in version.intervals_for(UnicodeCategory::Lu) {
+                assert!(union.iter().any(|(l, r)| l <= left && right <= r));
+            }
+        }
+        assert!(
+            intervals::count(&union)
+                > intervals::count(UnicodeVersion::V9_0_0.intervals_for(UnicodeCategory::Lu))
+        );
+    }
+
+    #[test]
+    fn test_interval_set_excluding_categories() {
+        let version = UnicodeVersion::V15_0_0;
+        let interval_set = version.interval_set_excluding_categories(UnicodeCategory::L);
+        assert!(!interval_set.contains('A'));
+        assert!(!interval_set.contains('ж'));
+        assert!(interval_set.contains('1'));
+        let letters = version
+            .interval_set(UnicodeCategory::L, None, None, None, None, None)
+            .expect("Invalid query");
+        assert_eq!(
+            interval_set.len().checked_add(letters.len()),
+            Some(0x110000)
+        );
+    }
+
+    #[test]
+    fn test_control_set() {
+        let interval_set = UnicodeVersion::V15_0_0.control_set();
+        assert!(interval_set.contains('\n'));
+        assert!(interval_set.contains('\t'));
+        assert!(interval_set.contains('\u{7f}'));
+        assert!(!interval_set.contains('A'));
+        assert_eq!(interval_set.len(), 65);
+    }
+
+    #[test]
+    fn test_assigned_set() {
+        let interval_set = UnicodeVersion::V15_0_0.assigned_set();
+        assert!(interval_set.contains('A'));
+        assert!(interval_set.contains(0xe000_u32));
+        // Unassigned
+        assert!(!interval_set.contains(0x0378_u32));
+        assert!(!interval_set.contains(0x10ffff_u32));
+        assert_eq!(interval_set.count_in(0xd800, 0xdfff), 0);
+    }
+
+    #[test]
+    fn test_category_timeline() {
+        let timeline = category_timeline(UnicodeCategory::Lo);
+        assert_eq!(timeline.len().checked_add(1), Some(ALL_VERSIONS.len()));
+        for (older, newer, added) in &timeline {
+            // Unicode 12.1 added only one character, and it is not a letter
+            if *older == UnicodeVersion::V12_0_0 {
+                assert!(added.is_empty());
+            } else {
+                assert!(!added.is_empty());
+            }
+            assert!(
+                intervals::intersect(added, older.intervals_for(UnicodeCategory::Lo)).is_empty()
+            );
+            assert_eq!(
+                intervals::intersect(added, newer.intervals_for(UnicodeCategory::Lo)),
+                *added
+            );
+        }
+        let timeline = category_timeline(UnicodeCategory::So);
+        assert_eq!(timeline[3].2, vec![(0x32ff, 0x32ff)]);
+    }
+
+    #[test]
+    fn test_set_from_predicate() {
+        let version = UnicodeVersion::V15_0_0;
+        let digits = version.set_from_predicate(0, 127, |codepoint| {
+            char::from_u32(codepoint).is_some_and(|c| c.is_ascii_digit())
+        });
+        assert_eq!(digits.len(), 10);
+        assert!(digits.contains('0'));
+        assert!(digits.contains('9'));
+        assert!(!digits.contains('a'));
+        let even = version.set_from_predicate(0, 5, |codepoint| codepoint % 2 == 0);
+        assert_eq!(even.len(), 3);
+        assert!(version.set_from_predicate(10, 0, |_| true).is_empty());
+        assert_eq!(
+            version
+                .set_from_predicate(MAX_CODEPOINT, u32::MAX, |_| true)
+                .len(),
+            1
+        );
+    }
+
+    #[test_case(UnicodeCategory::Lu)]
+    #[test_case(UnicodeCategory::Cn)]
+    #[test_case(UnicodeCategory::Zl)]
+    fn test_stable_intervals(category: UnicodeCategory) {
+        let stable = stable_intervals(category);
+        assert!(!stable.is_empty());
+        for version in ALL_VERSIONS {
+            let intervals = version.intervals_for(category);
+            assert_eq!(intervals::intersect(&stable, intervals), stable);
+        }
+    }
+
+    #[test]
+    fn test_removed_codepoints() {
+        for window in ALL_VERSIONS.windows(2) {
+            assert!(window[0].removed_codepoints(window[1]).is_empty());
+        }
+        // Codepoints assigned after 9.0.0 are unassigned there
+        let removed = UnicodeVersion::V15_0_0.removed_codepoints(UnicodeVersion::V9_0_0);
+        assert!(!removed.is_empty());
+        assert!(removed.iter().all(|(left, right)| UnicodeVersion::V9_0_0
+            .intervals_for(UnicodeCategory::Cn)
+            .iter()
+            .any(|(cn_left, cn_right)| cn_left <= left && right <= cn_right)));
+    }
+
+    #[test_case(UnicodeVersion::V9_0_0, UnicodeCategory::Lu)]
+    #[test_case(UnicodeVersion::V15_0_0, UnicodeCategory::Lo)]
+    #[test_case(UnicodeVersion::V15_0_0, UnicodeCategory::Zl)]
+    fn test_interval_set_for_category(version: UnicodeVersion, category: UnicodeCategory) {
+        let first = version.interval_set_for_category(category);
+        let second = version.interval_set_for_category(category);
+        assert!(Arc::ptr_eq(&first, &second));
+        let expected: Vec<_> = version
+            .intervals_for(category)
+            .iter()
+            .flat_map(|(left, right)| *left..=*right)
+            .collect();
+        assert_eq!(first.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_vowel_consonant_sets() {
+        let (vowels, consonants) = UnicodeVersion::V15_0_0.vowel_consonant_sets();
+        assert_eq!(vowels.len(), 10);
+        assert_eq!(consonants.len(), 42);
+        assert!(vowels.contains('a'));
+        assert!(!vowels.contains('b'));
+        assert!(consonants.contains('b'));
+        assert!(!consonants.contains('a'));
+        assert!(consonants.contains('Z'));
+    }
+
+    #[test]
+    fn test_category_weights_in() {
+        let weights = UnicodeVersion::V15_0_0.category_weights_in(0, 127);
+        assert_eq!(
+            weights,
+            vec![
+                (UnicodeCategory::Pe, 3),
+                (UnicodeCategory::Pc, 1),
+                (UnicodeCategory::Cc, 33),
+                (UnicodeCategory::Sc, 1),
+                (UnicodeCategory::Pd, 1),
+                (UnicodeCategory::Nd, 10),
+                (UnicodeCategory::Ll, 26),
+                (UnicodeCategory::Sm, 6),
+                (UnicodeCategory::Sk, 2),
+                (UnicodeCategory::Ps, 3),
+                (UnicodeCategory::Po, 15),
+                (UnicodeCategory::Zs, 1),
+                (UnicodeCategory::Lu, 26),
+            ]
+        );
+        let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        assert_eq!(total, 128);
+        assert!(UnicodeVersion::V15_0_0
+            .category_weights_in(65, 64)
+            .is_empty());
+    }
+
     #[test_case(UnicodeVersion::V9_0_0, "9.0.0")]
     #[test_case(UnicodeVersion::V10_0_0, "10.0.0")]
     #[test_case(UnicodeVersion::V11_0_0, "11.0.0")]
@@ -825,7 +2287,7 @@ mod tests {
         let version = UnicodeVersion::V15_0_0;
         let mut hasher = DefaultHasher::new();
         version.hash(&mut hasher);
-        hasher.finish();
+        let _ = hasher.finish();
         let _ = version.clone();
         assert_eq!(format!("{version:?}"), "V15_0_0");
     }
@@ -836,7 +2298,7 @@ mod tests {
         let _ = query.clone();
         assert_eq!(
             format!("{query:?}"), 
-            "IntervalQuery { version: V15_0_0, include_categories: None, exclude_categories: None, include_characters: None, exclude_characters: None, min_codepoint: 0, max_codepoint: 1114111 }"
+            "IntervalQuery { version: V15_0_0, include_categories: None, exclude_categories: None, include_characters: None, exclude_characters: None, exclude_codepoints: None, min_codepoint: 0, max_codepoint: 1114111, password_safe: false }"
         );
         assert_eq!(query, query);
     }
@@ -1,11 +1,22 @@
 use crate::{
     categories,
-    categories::UnicodeCategorySet,
-    constants::{ALL_CATEGORIES, MAX_CODEPOINT},
+    categories::{UnicodeCategory, UnicodeCategorySet},
+    constants::{ALL_CATEGORIES, MAX_CODEPOINT, VERSION_COUNT},
     intervals, Interval, UnicodeVersion,
 };
 use core::cmp::{max, min};
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::OnceLock};
+
+/// Category groups whose merged intervals are cached after the first use.
+const GROUPS: [UnicodeCategorySet; 7] = [
+    UnicodeCategory::L,
+    UnicodeCategory::M,
+    UnicodeCategory::N,
+    UnicodeCategory::P,
+    UnicodeCategory::S,
+    UnicodeCategory::Z,
+    UnicodeCategory::C,
+];
 
 /// Non-generic query implementation to reduce the amount of generated code.
 #[must_use]
@@ -59,7 +70,8 @@ pub fn query(
             intervals
         }
     };
-    // Include intervals
+    // Include intervals. If no categories matched (e.g. an empty `include_categories` set),
+    // then the result consists only of the included characters
     if intervals.is_empty() {
         intervals = include_intervals;
     } else if !include_intervals.is_empty() {
@@ -87,26 +99,50 @@ pub fn intervals_for_set(
             if categories.len() == 1 {
                 let category_idx = value.trailing_zeros() as usize;
                 Cow::Borrowed(version.table()[category_idx])
+            } else if let Some(group_idx) = GROUPS.iter().position(|group| *group == categories) {
+                Cow::Borrowed(&group_intervals(version)[group_idx])
             } else {
-                // Pre-allocate space for intervals from all categories
-                let size: usize = categories
-                    .iter()
-                    .map(|c| version.table()[c as usize].len())
-                    .sum();
-                let mut intervals = Vec::with_capacity(size);
-                for category in categories.iter() {
-                    intervals.extend_from_slice(version.table()[category as usize]);
-                }
-                Cow::Owned(intervals)
+                Cow::Owned(concat_intervals(version, categories))
             }
         }
     }
 }
 
+/// Concatenate intervals of all categories in the set.
+/// The final intervals are not merged.
+fn concat_intervals(version: UnicodeVersion, categories: UnicodeCategorySet) -> Vec<Interval> {
+    // Pre-allocate space for intervals from all categories
+    let size: usize = categories
+        .iter()
+        .map(|c| version.table()[c as usize].len())
+        .sum();
+    let mut intervals = Vec::with_capacity(size);
+    for category in categories {
+        intervals.extend_from_slice(version.table()[category as usize]);
+    }
+    intervals
+}
+
+/// Merged intervals for every category group in `GROUPS`.
+/// They are computed once per Unicode version and reused afterwards.
+fn group_intervals(version: UnicodeVersion) -> &'static [Vec<Interval>; GROUPS.len()] {
+    // `OnceLock` is not `Copy`, therefore a constant is needed to initialize the array.
+    // Every usage of a constant creates a new instance which is exactly what is needed here
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: OnceLock<[Vec<Interval>; GROUPS.len()]> = OnceLock::new();
+    static CACHE: [OnceLock<[Vec<Interval>; GROUPS.len()]>; VERSION_COUNT] = [EMPTY; VERSION_COUNT];
+    CACHE[version as usize].get_or_init(|| {
+        GROUPS.map(|group| {
+            let mut intervals = concat_intervals(version, group);
+            intervals::merge(&mut intervals);
+            intervals
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::UnicodeCategory;
     use test_case::test_case;
 
     #[test_case(UnicodeCategorySet::new(), &[])]
@@ -117,4 +153,35 @@ mod tests {
         let intervals = intervals_for_set(UnicodeVersion::V15_0_0, categories);
         assert_eq!(intervals, expected);
     }
+
+    #[test_case(UnicodeVersion::V9_0_0)]
+    #[test_case(UnicodeVersion::V12_1_0)]
+    #[test_case(UnicodeVersion::V15_0_0)]
+    fn test_intervals_for_group(version: UnicodeVersion) {
+        for group in GROUPS {
+            let mut expected = concat_intervals(version, group);
+            intervals::merge(&mut expected);
+            assert_eq!(intervals_for_set(version, group), expected);
+            // Cached intervals are reused
+            assert!(matches!(
+                intervals_for_set(version, group),
+                Cow::Borrowed(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_intervals_for_group_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    intervals_for_set(UnicodeVersion::V14_0_0, UnicodeCategory::L).into_owned()
+                })
+            })
+            .collect();
+        let expected = intervals_for_set(UnicodeVersion::V14_0_0, UnicodeCategory::L);
+        for handle in handles {
+            assert_eq!(expected, handle.join().expect("Thread panicked"));
+        }
+    }
 }
@@ -0,0 +1,158 @@
+use crate::{UnicodeCategory, UnicodeCategorySet};
+use core::{fmt, str::FromStr};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl Serialize for UnicodeCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnicodeCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CategoryVisitor;
+
+        impl<'de> Visitor<'de> for CategoryVisitor {
+            type Value = UnicodeCategory;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a Unicode category")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                UnicodeCategory::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CategoryVisitor)
+    }
+}
+
+/// Serialized as a sequence of category abbreviations.
+impl Serialize for UnicodeCategorySet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for category in self {
+            seq.serialize_element(category.as_str())?;
+        }
+        seq.end()
+    }
+}
+
+/// In human-readable formats, deserialized either from a sequence of categories or from a
+/// comma-separated string. Category groups, like "L", are accepted in both forms.
+/// Other formats expect a sequence, as produced by `Serialize`.
+impl<'de> Deserialize<'de> for UnicodeCategorySet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CategorySetVisitor;
+
+        impl<'de> Visitor<'de> for CategorySetVisitor {
+            type Value = UnicodeCategorySet;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence of Unicode categories or a comma-separated string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                UnicodeCategorySet::from_str(value).map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut set = UnicodeCategorySet::new();
+                while let Some(value) = seq.next_element::<String>()? {
+                    set |= UnicodeCategorySet::from_str(&value).map_err(de::Error::custom)?;
+                }
+                Ok(set)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CategorySetVisitor)
+        } else {
+            // Formats that are not self-describing do not support `deserialize_any`
+            deserializer.deserialize_seq(CategorySetVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(UnicodeCategory::Lu, "\"Lu\"")]
+    #[test_case(UnicodeCategory::Cn, "\"Cn\"")]
+    fn test_category_roundtrip(category: UnicodeCategory, expected: &str) {
+        let serialized = serde_json::to_string(&category).expect("Serialization failed");
+        assert_eq!(serialized, expected);
+        let deserialized: UnicodeCategory =
+            serde_json::from_str(&serialized).expect("Deserialization failed");
+        assert_eq!(deserialized, category);
+    }
+
+    #[test]
+    fn test_category_full_name() {
+        let deserialized: UnicodeCategory =
+            serde_json::from_str("\"Uppercase Letter\"").expect("Deserialization failed");
+        assert_eq!(deserialized, UnicodeCategory::Lu);
+    }
+
+    #[test_case(UnicodeCategorySet::new(), "[]")]
+    #[test_case(UnicodeCategory::Lu | UnicodeCategory::Nd, "[\"Nd\",\"Lu\"]")]
+    #[test_case(UnicodeCategory::L, "[\"Ll\",\"Lm\",\"Lo\",\"Lt\",\"Lu\"]")]
+    fn test_set_roundtrip(set: UnicodeCategorySet, expected: &str) {
+        let serialized = serde_json::to_string(&set).expect("Serialization failed");
+        assert_eq!(serialized, expected);
+        let deserialized: UnicodeCategorySet =
+            serde_json::from_str(&serialized).expect("Deserialization failed");
+        assert_eq!(deserialized, set);
+    }
+
+    #[test_case(UnicodeCategory::Lu)]
+    #[test_case(UnicodeCategory::Cn)]
+    fn test_category_roundtrip_binary(category: UnicodeCategory) {
+        let serialized = bincode::serialize(&category).expect("Serialization failed");
+        let deserialized: UnicodeCategory =
+            bincode::deserialize(&serialized).expect("Deserialization failed");
+        assert_eq!(deserialized, category);
+    }
+
+    #[test_case(UnicodeCategorySet::new())]
+    #[test_case(UnicodeCategory::Lu | UnicodeCategory::Nd)]
+    #[test_case(UnicodeCategorySet::all())]
+    fn test_set_roundtrip_binary(set: UnicodeCategorySet) {
+        let serialized = bincode::serialize(&set).expect("Serialization failed");
+        let deserialized: UnicodeCategorySet =
+            bincode::deserialize(&serialized).expect("Deserialization failed");
+        assert_eq!(deserialized, set);
+    }
+
+    #[test_case("\"Lu, Nd\"", UnicodeCategory::Lu | UnicodeCategory::Nd)]
+    #[test_case("\"\"", UnicodeCategorySet::new())]
+    #[test_case("[\"L\", \"Nd\"]", UnicodeCategory::L | UnicodeCategory::Nd)]
+    #[test_case("[\"L\\u0075\"]", UnicodeCategory::Lu.into(); "escaped")]
+    fn test_set_deserialize(value: &str, expected: UnicodeCategorySet) {
+        let deserialized: UnicodeCategorySet =
+            serde_json::from_str(value).expect("Deserialization failed");
+        assert_eq!(deserialized, expected);
+    }
+
+    #[test_case("\"Xx\"")]
+    #[test_case("42")]
+    fn test_category_deserialize_error(value: &str) {
+        assert!(serde_json::from_str::<UnicodeCategory>(value).is_err());
+    }
+
+    #[test_case("\"Lu, Xx\"", "'Xx' is not a valid Unicode category")]
+    #[test_case("[\"Lu\", \"Xx\"]", "'Xx' is not a valid Unicode category")]
+    fn test_set_deserialize_error(value: &str, expected: &str) {
+        let error = serde_json::from_str::<UnicodeCategorySet>(value)
+            .expect_err("Should fail")
+            .to_string();
+        assert!(error.starts_with(expected), "{error}");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    constants::MAX_CODEPOINT, intervals, query, Error, IntervalSet, UnicodeCategorySet,
+    UnicodeVersion,
+};
+use core::str::FromStr;
+
+/// Build an `IntervalSet` from a compact spec string.
+///
+/// A spec consists of whitespace-separated clauses:
+///
+///   - `Lu,Ll` - comma-separated Unicode categories or category groups (e.g. `L`) to include;
+///   - `+0x2600-0x26FF` - a codepoint range to include. A single codepoint (`+0x2603`) is
+///     also accepted. Codepoints are either hexadecimal with the `0x` prefix or decimal;
+///   - `-ABC` - characters to exclude.
+///
+/// Clauses can be repeated and come in any order. Without a categories clause, no categories
+/// are included. Excluded characters are removed after everything else is included.
+/// Whitespace characters can't be excluded as they separate clauses.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_intervals::UnicodeVersion;
+///
+/// let interval_set = unicode_intervals::parse_spec("Lu +0x2600-0x26FF -ABC", UnicodeVersion::V15_0_0)
+///     .expect("Invalid spec");
+/// assert!(interval_set.contains('D'));
+/// assert!(interval_set.contains('☃'));
+/// assert!(!interval_set.contains('A'));
+/// ```
+///
+/// # Errors
+///
+///   - Unknown Unicode category
+///   - Malformed codepoint range
+///   - Range minimum is greater than its maximum or codepoints are `> 1114111`
+pub fn parse_spec(spec: &str, version: UnicodeVersion) -> Result<IntervalSet, Error> {
+    let mut categories = UnicodeCategorySet::new();
+    let mut include = vec![];
+    let mut exclude = String::new();
+    for clause in spec.split_whitespace() {
+        if let Some(range) = clause.strip_prefix('+') {
+            include.push(parse_range(range, clause)?);
+        } else if let Some(characters) = clause.strip_prefix('-') {
+            exclude.push_str(characters);
+        } else {
+            categories |= UnicodeCategorySet::from_str(clause)?;
+        }
+    }
+    let mut intervals = query::intervals_for_set(version, categories).into_owned();
+    intervals.extend_from_slice(&include);
+    intervals::merge(&mut intervals);
+    let intervals = intervals::subtract(intervals, &intervals::from_str(&exclude));
+    Ok(IntervalSet::new(intervals))
+}
+
+fn parse_range(range: &str, clause: &str) -> Result<(u32, u32), Error> {
+    let (minimum, maximum) = match range.split_once('-') {
+        Some((minimum, maximum)) => (
+            parse_codepoint(minimum, clause)?,
+            parse_codepoint(maximum, clause)?,
+        ),
+        None => {
+            let codepoint = parse_codepoint(range, clause)?;
+            (codepoint, codepoint)
+        }
+    };
+    if minimum > MAX_CODEPOINT || maximum > MAX_CODEPOINT {
+        return Err(Error::CodepointNotInRange(minimum, maximum));
+    }
+    if minimum > maximum {
+        return Err(Error::InvalidCodepoints(minimum, maximum));
+    }
+    Ok((minimum, maximum))
+}
+
+fn parse_codepoint(value: &str, clause: &str) -> Result<u32, Error> {
+    let error = || Error::InvalidSpec(clause.to_owned().into_boxed_str());
+    let (digits, radix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => (hex, 16),
+        None => (value, 10),
+    };
+    // `u32::from_str_radix` accepts a leading `+`, but only digits are valid here
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(error());
+    }
+    u32::from_str_radix(digits, radix).map_err(|_| error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", &[]; "empty")]
+    #[test_case("Zl,Zp", &[(8232, 8233)]; "categories")]
+    #[test_case("Zl Zp", &[(8232, 8233)]; "repeated categories")]
+    #[test_case("+0x41-0x43", &[(65, 67)]; "hex range")]
+    #[test_case("+65-67 +0x2603", &[(65, 67), (9731, 9731)]; "decimal range and single")]
+    #[test_case("+0x41-0x45 -BD", &[(65, 65), (67, 67), (69, 69)]; "excluded characters")]
+    #[test_case("Zl +0x41-0x43 -B", &[(65, 65), (67, 67), (8232, 8232)]; "all clauses")]
+    #[test_case("-A Zl", &[(8232, 8232)]; "any order")]
+    fn test_parse_spec(spec: &str, expected: &[(u32, u32)]) {
+        let interval_set = parse_spec(spec, UnicodeVersion::V15_0_0).expect("Invalid spec");
+        let codepoints: Vec<_> = interval_set.iter().collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .flat_map(|(left, right)| *left..=*right)
+            .collect();
+        assert_eq!(codepoints, expected);
+    }
+
+    #[test_case("Xx", "'Xx' is not a valid Unicode category")]
+    #[test_case("Lu,", "'' is not a valid Unicode category")]
+    #[test_case("+0xZZ", "'+0xZZ' is not a valid spec clause")]
+    #[test_case("+", "'+' is not a valid spec clause")]
+    #[test_case("+1-", "'+1-' is not a valid spec clause")]
+    #[test_case("++5", "'++5' is not a valid spec clause")]
+    #[test_case("+0x+5", "'+0x+5' is not a valid spec clause")]
+    #[test_case("+1-+5", "'+1-+5' is not a valid spec clause")]
+    #[test_case("+0x", "'+0x' is not a valid spec clause")]
+    #[test_case(
+        "+0x43-0x41",
+        "Minimum codepoint should be less or equal than maximum codepoint. Got 67 < 65"
+    )]
+    #[test_case(
+        "+0x110000",
+        "Codepoints should be in [0; 1114111] range. Got: [1114112; 1114112]"
+    )]
+    fn test_parse_spec_error(spec: &str, expected: &str) {
+        assert_eq!(
+            parse_spec(spec, UnicodeVersion::V15_0_0)
+                .expect_err("Should fail")
+                .to_string(),
+            expected
+        );
+    }
+}